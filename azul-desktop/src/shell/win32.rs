@@ -21,12 +21,13 @@ use azul_core::{
     gl::OptionGlContextPtr,
     task::{Thread, ThreadId, Timer, TimerId},
     window::{
-        LogicalSize, Menu, MenuCallback, MenuItem,
+        LogicalSize, Menu, MenuCallback, MenuItem, Monitor,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType,
+        MouseCursorType, Vsync, WindowTheme,
     },
 };
+use azul_css::{AzString, LayoutPoint, LayoutSize};
 use core::{
     fmt,
     cell::{BorrowError, BorrowMutError, RefCell},
@@ -47,14 +48,15 @@ use webrender::{
     },
     render_api::RenderApi as WrRenderApi,
     PipelineInfo as WrPipelineInfo, Renderer as WrRenderer, RendererError as WrRendererError,
-    RendererOptions as WrRendererOptions, ShaderPrecacheFlags as WrShaderPrecacheFlags,
+    RendererOptions as WrRendererOptions, SceneBuilderHooks as WrSceneBuilderHooks,
+    SceneSwapResult as WrSceneSwapResult, ShaderPrecacheFlags as WrShaderPrecacheFlags,
     Shaders as WrShaders, Transaction as WrTransaction,
 };
 use winapi::{
     shared::{
-        minwindef::{BOOL, HINSTANCE, LPARAM, LRESULT, TRUE, UINT, WPARAM},
+        minwindef::{BOOL, FALSE, HINSTANCE, LPARAM, LRESULT, TRUE, UINT, WPARAM},
         ntdef::HRESULT,
-        windef::{HDC, HGLRC, HMENU, HWND, RECT},
+        windef::{HACCEL, HDC, HGLRC, HMENU, HWND, RECT},
     },
     ctypes::wchar_t,
     um::dwmapi::{DWM_BB_ENABLE, DWM_BLURBEHIND},
@@ -74,12 +76,12 @@ const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
 const AZ_REDO_HIT_TEST: u32 = WM_APP + 3;
 const AZ_GPU_SCROLL_RENDER: u32 = WM_APP + 4;
+// Posted by `Notifier` from WebRender's render / scene-builder thread once a
+// frame is ready, so the owning window's WM_PAINT handler composites it.
+const AZ_COMPOSITE_NEEDED: u32 = WM_APP + 5;
 
 const CLASS_NAME: &str = "AzulApplicationClass";
 
-// TODO: Cache compiled shaders between renderers
-const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
-
 trait RectTrait {
     fn width(&self) -> u32;
     fn height(&self) -> u32;
@@ -95,7 +97,154 @@ impl RectTrait for RECT {
 }
 
 pub fn get_monitors(app: &App) -> MonitorVec {
-    MonitorVec::from_const_slice(&[]) // TODO
+    let monitors = enumerate_monitors();
+    MonitorVec::from_vec(monitors)
+}
+
+/// Attempts `SetProcessDpiAwarenessContext(PER_MONITOR_AWARE_V2)` (user32.dll,
+/// Win10 1703+) and then `SetProcessDpiAwareness(PER_MONITOR_DPI_AWARE)`
+/// (shcore.dll, Win8.1), returning `true` if either succeeds.
+unsafe fn set_process_dpi_aware_v2() -> bool {
+    use winapi::ctypes::c_void;
+    use winapi::um::libloaderapi::GetProcAddress;
+
+    // DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2 == (HANDLE)-4
+    const PER_MONITOR_AWARE_V2: isize = -4;
+    // PROCESS_PER_MONITOR_DPI_AWARE == 2
+    const PROCESS_PER_MONITOR_DPI_AWARE: u32 = 2;
+
+    if let Some(user32) = load_dll("user32.dll") {
+        let mut name = encode_ascii("SetProcessDpiAwarenessContext");
+        let proc = GetProcAddress(user32, name.as_mut_ptr());
+        if !proc.is_null() {
+            let f: extern "system" fn(*mut c_void) -> BOOL = mem::transmute(proc);
+            if f(PER_MONITOR_AWARE_V2 as *mut c_void) == TRUE {
+                return true;
+            }
+        }
+    }
+
+    if let Some(shcore) = load_dll("shcore.dll") {
+        let mut name = encode_ascii("SetProcessDpiAwareness");
+        let proc = GetProcAddress(shcore, name.as_mut_ptr());
+        if !proc.is_null() {
+            let f: extern "system" fn(u32) -> HRESULT = mem::transmute(proc);
+            // S_OK == 0, E_ACCESSDENIED if already set — treat both as success
+            let hr = f(PROCESS_PER_MONITOR_DPI_AWARE);
+            if hr == 0 || hr == winapi::shared::winerror::E_ACCESSDENIED {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Enumerates all attached displays via `EnumDisplayMonitors`, filling in each
+/// monitor's bounds, primary flag and per-monitor effective DPI.
+fn enumerate_monitors() -> Vec<Monitor> {
+    use winapi::shared::windef::{HDC, HMONITOR, LPRECT};
+    use winapi::um::winuser::EnumDisplayMonitors;
+
+    // Collect the raw HMONITOR handles through the enumeration callback, then
+    // resolve each one; doing the heavier GetMonitorInfo / DPI work outside the
+    // callback keeps the callback allocation-free.
+    unsafe extern "system" fn proc(
+        hmonitor: HMONITOR,
+        _hdc: HDC,
+        _rect: LPRECT,
+        userdata: LPARAM,
+    ) -> BOOL {
+        let handles = &mut *(userdata as *mut Vec<HMONITOR>);
+        handles.push(hmonitor);
+        TRUE
+    }
+
+    let mut handles: Vec<HMONITOR> = Vec::new();
+    unsafe {
+        EnumDisplayMonitors(
+            ptr::null_mut(),
+            ptr::null_mut(),
+            Some(proc),
+            &mut handles as *mut Vec<HMONITOR> as LPARAM,
+        );
+    }
+
+    handles
+        .into_iter()
+        .enumerate()
+        .filter_map(|(id, hmonitor)| resolve_monitor(id, hmonitor))
+        .collect()
+}
+
+/// Builds a [`Monitor`] from an `HMONITOR`, reading its device name and bounds
+/// from `GetMonitorInfoW` and its scale factor from `GetDpiForMonitor`.
+fn resolve_monitor(id: usize, hmonitor: winapi::shared::windef::HMONITOR) -> Option<Monitor> {
+    use winapi::um::winuser::{GetMonitorInfoW, MONITORINFOEXW, MONITORINFOF_PRIMARY};
+
+    let mut info: MONITORINFOEXW = unsafe { mem::zeroed() };
+    info.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
+    if unsafe { GetMonitorInfoW(hmonitor, &mut info as *mut _ as *mut _) } == 0 {
+        return None;
+    }
+
+    let bounds = info.rcMonitor;
+    let is_primary = (info.dwFlags & MONITORINFOF_PRIMARY) != 0;
+    let name = {
+        let len = info
+            .szDevice
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(info.szDevice.len());
+        String::from_utf16_lossy(&info.szDevice[..len])
+    };
+
+    let scale_factor = monitor_scale_factor(hmonitor);
+
+    Some(Monitor {
+        id,
+        name: Some(name.into()).into(),
+        size: LayoutSize::new(bounds.width() as isize, bounds.height() as isize),
+        position: LayoutPoint::new(bounds.left as isize, bounds.top as isize),
+        scale_factor,
+        video_modes: Vec::new().into(),
+        is_primary_monitor: is_primary,
+    })
+}
+
+/// Queries MDT_EFFECTIVE_DPI for `hmonitor` via `GetDpiForMonitor`
+/// (shcore.dll), falling back to 1.0 when the function is unavailable.
+fn monitor_scale_factor(hmonitor: winapi::shared::windef::HMONITOR) -> f64 {
+    use winapi::um::libloaderapi::GetProcAddress;
+
+    // MDT_EFFECTIVE_DPI == 0
+    const MDT_EFFECTIVE_DPI: u32 = 0;
+
+    let shcore = match load_dll("shcore.dll") {
+        Some(s) => s,
+        None => return 1.0,
+    };
+
+    let mut name = encode_ascii("GetDpiForMonitor");
+    let proc = unsafe { GetProcAddress(shcore, name.as_mut_ptr()) };
+    if proc.is_null() {
+        return 1.0;
+    }
+
+    let f: extern "system" fn(
+        winapi::shared::windef::HMONITOR,
+        u32,
+        *mut u32,
+        *mut u32,
+    ) -> HRESULT = unsafe { mem::transmute(proc) };
+
+    let mut dpi_x = 96u32;
+    let mut dpi_y = 96u32;
+    if f(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) != 0 {
+        return 1.0;
+    }
+
+    dpi_x.max(dpi_y) as f64 / 96.0
 }
 
 /// Main function that starts when app.run() is invoked
@@ -110,10 +259,11 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
             winuser::{
                 DispatchMessageW, GetDC, GetMessageW,
                 RegisterClassW, ReleaseDC, SetProcessDPIAware,
-                TranslateMessage, MsgWaitForMultipleObjects,
+                TranslateMessage, MsgWaitForMultipleObjectsEx,
                 PeekMessageW, GetForegroundWindow,
-                CS_HREDRAW, CS_OWNDC, QS_ALLEVENTS,
-                CS_VREDRAW, MSG, WNDCLASSW, PM_NOREMOVE, PM_NOYIELD
+                CS_HREDRAW, CS_OWNDC, QS_ALLEVENTS, QS_ALLINPUT,
+                CS_VREDRAW, MSG, WNDCLASSW, PM_NOREMOVE, PM_NOYIELD,
+                PM_REMOVE, MWMO_INPUTAVAILABLE, WM_QUIT,
             }
         },
     };
@@ -123,12 +273,24 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         return Err(WindowsStartupError::NoAppInstance(get_last_error()));
     }
 
-    // Tell windows that this process is DPI-aware
+    // Tell windows that this process is DPI-aware. Prefer the newest mode the
+    // OS offers so windows dragged between monitors of different scale factors
+    // report a correct LogicalSize:
+    //   Win10 1703+  SetProcessDpiAwarenessContext(PER_MONITOR_AWARE_V2)
+    //   Win8.1       SetProcessDpiAwareness(PER_MONITOR_DPI_AWARE)
+    //   Vista        SetProcessDPIAware()            (system DPI only)
+    unsafe {
+        if !set_process_dpi_aware_v2() {
+            SetProcessDPIAware();
+        }
+    }
+
+    // Initialize OLE on the event-loop thread so `RegisterDragDrop` works. This
+    // must happen on the same (STA) thread that pumps the message loop, since
+    // drag-and-drop callbacks are delivered there.
     unsafe {
-        SetProcessDPIAware();
-    } // Vista
-      // SetProcessDpiAwareness(); Win8.1
-      // unsafe { SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE); } // Win10
+        winapi::um::ole2::OleInitialize(ptr::null_mut());
+    }
 
     // Register the application class (shared between windows)
     let mut class_name = encode_wide(CLASS_NAME);
@@ -162,6 +324,8 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
         fc_cache,
         windows: BTreeMap::new(),
         dwm,
+        shared_context: None,
+        shader_cache: None,
     }));
 
     for opts in windows {
@@ -180,90 +344,77 @@ pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsS
             .insert(w.get_id(), w);
     }
 
-    // Process the window messages one after another
+    // Process the window messages one after another.
     //
-    // Multiple windows will process messages in sequence
-    // to avoid complicated multithreading logic
+    // All windows run on this one thread and therefore share a single thread
+    // message queue, so a single wait covers every `hwnd`: we block in
+    // `MsgWaitForMultipleObjectsEx(.., QS_ALLINPUT, MWMO_INPUTAVAILABLE)` until
+    // any window has input, then drain *all* pending messages with
+    // `PeekMessageW(PM_REMOVE)` before blocking again. This keeps idle CPU at
+    // zero (no 1ms sleep spin) while preserving wake-up-granularity latency.
+    // `WM_TIMER` (AZ_THREAD_TICK / AZ_TICK_REGENERATE_DOM) flows through the same
+    // drain loop like any other message.
     let mut msg: MSG = unsafe { mem::zeroed() };
-    let mut results = Vec::new();
-    let mut hwnds = Vec::new();
 
     'main: loop {
 
-        {
-            let app = match app_data_inner.try_borrow().ok() {
-                Some(s) => s,
-                None => break 'main, // borrow error
-            };
-
-            for win in app.windows.values() {
-                hwnds.push(win.hwnd);
+        // Drain every message currently queued for this thread. Passing a null
+        // `hwnd` picks up messages for all of this thread's windows at once.
+        while unsafe { PeekMessageW(&mut msg, ptr::null_mut(), 0, 0, PM_REMOVE) } != FALSE {
+            if msg.message == WM_QUIT {
+                // zero-return / WM_QUIT semantics from the old GetMessageW loop
+                break 'main;
             }
-        }
-
-        // For single-window apps, GetMessageW will block until
-        // the next event comes in. For multi-window apps we have
-        // to use PeekMessage in order to not block in case that
-        // there are no messages for that window
-
-        let is_multiwindow = match hwnds.len() {
-            0 | 1 => false,
-            _ => true,
-        };
 
-        if is_multiwindow {
+            // Give the target window's menu accelerators first crack at the
+            // message. The borrow is dropped before dispatch so the re-entrant
+            // `WindowProc` can borrow the application data itself.
+            let accel = match app_data_inner.try_borrow() {
+                Ok(app) => app
+                    .windows
+                    .get(&(msg.hwnd as usize))
+                    .and_then(|w| w.accel_table),
+                Err(_) => None,
+            };
 
-            for hwnd in hwnds.iter() {
-                unsafe {
-                    let r = PeekMessageW(&mut msg, *hwnd, 0, 0, PM_NOREMOVE);
-
-                    if r > 0 {
-                        // new message available
-                        let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                        results.push(r);
-                    }
-                }
-            }
+            let translated = match accel {
+                Some(h) => unsafe {
+                    winapi::um::winuser::TranslateAcceleratorW(msg.hwnd, h, &mut msg) != 0
+                },
+                None => false,
+            };
 
-            // It would be great if there was a function like
-            // MsgWaitForMultipleObjects([hwnd]), so that you could
-            // wait on one of many input events
-            //
-            // The best workaround is to get the foreground window
-            // (that the user is interacting with) and then
-            // wait until some event happens to that foreground window
-            let mut dump_msg: MSG = unsafe { mem::zeroed() };
-            while !hwnds.iter().any(|hwnd| unsafe { PeekMessageW(&mut dump_msg, *hwnd, 0, 0, PM_NOREMOVE) > 0 }) {
-                // reduce CPU load for multi-window apps
-                std::thread::sleep(std::time::Duration::from_millis(1));
-            }
-        } else {
-            for hwnd in hwnds.iter() {
+            // `TranslateAcceleratorW` already dispatched a WM_COMMAND when it
+            // matched a binding, so skip the normal translate/dispatch pair.
+            if !translated {
                 unsafe {
-                    let r = GetMessageW(&mut msg, *hwnd, 0, 0);
-                    if r > 0 {
-                        TranslateMessage(&msg);
-                        DispatchMessageW(&msg);
-                    }
-                    results.push(r);
+                    TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
                 }
             }
         }
 
-        for r in results.iter() {
-            if !(*r > 0) {
-                break 'main; // error occured
-            }
-        }
-
-        if hwnds.is_empty() {
+        // Exit once every window has been destroyed.
+        let has_windows = match app_data_inner.try_borrow() {
+            Ok(app) => !app.windows.is_empty(),
+            Err(_) => break 'main, // borrow error
+        };
+        if !has_windows {
             break 'main;
         }
 
-        hwnds.clear();
-        results.clear();
+        // Block until any window on this thread has input waiting.
+        // MWMO_INPUTAVAILABLE wakes even for input that arrived between the
+        // drain above and this wait, so no message is ever missed.
+        unsafe {
+            MsgWaitForMultipleObjectsEx(
+                0,
+                ptr::null(),
+                INFINITE,
+                QS_ALLINPUT,
+                MWMO_INPUTAVAILABLE,
+            );
+        }
     }
 
     Ok(msg.wParam as isize)
@@ -318,6 +469,15 @@ pub enum WindowsOpenGlError {
     NoMatchingPixelFormat(u32),
     OpenGLNotAvailable(u32),
     FailedToStoreContext(u32),
+    /// Neither `libEGL.dll` nor `libGLESv2.dll` (ANGLE) could be loaded, so the
+    /// EGL fallback is unavailable.
+    EglDllNotFound(u32),
+    /// `eglGetDisplay` / `eglInitialize` failed: no usable ANGLE display.
+    EglInitFailed(u32),
+    /// `eglChooseConfig` returned no framebuffer config matching the request.
+    EglNoMatchingConfig(u32),
+    /// `eglCreateContext` / `eglCreateWindowSurface` returned a null handle.
+    EglContextCreationFailed(u32),
 }
 
 #[derive(Debug)]
@@ -351,21 +511,104 @@ impl From<WindowsOpenGlError> for WindowsStartupError {
     }
 }
 
-struct Notifier {}
+/// Hands WebRender a way back into the owning window's message loop. WebRender
+/// calls `wake_up` / `new_frame_ready` from its own render and scene-builder
+/// threads, so this only stores the `HWND` as a plain `usize` (keeping
+/// `Notifier: Send`) and posts a custom message rather than touching any
+/// window state directly.
+struct Notifier {
+    hwnd: usize,
+    /// Mirrors the owning [`Window`]'s `pending_frames`; decremented here once
+    /// a frame WebRender was asked to generate is actually ready, so
+    /// [`Window::wait_for_pending_frame`] on the UI thread can tell when the
+    /// framebuffer is caught up with the latest transaction.
+    pending_frames: Arc<AtomicUsize>,
+}
+
+impl Notifier {
+    /// Posts `AZ_COMPOSITE_NEEDED` to the owning window so its `WM_PAINT`
+    /// handler schedules a present. `PostMessageW` is documented safe to call
+    /// from any thread, which is the only guarantee this relies on.
+    fn post_composite_needed(&self) {
+        use winapi::um::winuser::PostMessageW;
+        unsafe {
+            PostMessageW(self.hwnd as HWND, AZ_COMPOSITE_NEEDED, 0, 0);
+        }
+    }
+}
 
 impl WrRenderNotifier for Notifier {
     fn clone(&self) -> Box<dyn WrRenderNotifier> {
-        Box::new(Notifier {})
+        Box::new(Notifier {
+            hwnd: self.hwnd,
+            pending_frames: self.pending_frames.clone(),
+        })
+    }
+    fn wake_up(&self, _composite_needed: bool) {
+        self.post_composite_needed();
     }
-    fn wake_up(&self, composite_needed: bool) {}
     fn new_frame_ready(
         &self,
         _: WrDocumentId,
         _scrolled: bool,
-        composite_needed: bool,
+        _composite_needed: bool,
         _render_time: Option<u64>,
     ) {
+        // Saturating: a frame that was already in flight when the counter was
+        // last read as zero (e.g. right after window creation, before the
+        // first `request_frame`) must not wrap it around.
+        let _ = self
+            .pending_frames
+            .fetch_update(AtomicOrdering::SeqCst, AtomicOrdering::SeqCst, |n| {
+                Some(n.saturating_sub(1))
+            });
+        self.post_composite_needed();
+    }
+}
+
+/// Implemented by each platform backend so the WebRender scene-builder thread
+/// can tell it a new *scene* (as opposed to just a frame) became active.
+/// Distinct from `Notifier`'s frame-level wakeup: a scene swap means a whole
+/// new display list took effect, which is the right moment for an embedder to
+/// know async display-list/layout work it queued for that scene is now live.
+trait SceneSwapObserver: Send {
+    fn on_scene_swapped(&self, document_ids: &[WrDocumentId]);
+}
+
+impl SceneSwapObserver for Notifier {
+    fn on_scene_swapped(&self, _document_ids: &[WrDocumentId]) {
+        self.post_composite_needed();
+    }
+}
+
+/// `webrender::SceneBuilderHooks` implementation for win32: most of the hook
+/// points are no-ops here since azul doesn't yet do anything scene-builder
+/// side beyond what WebRender itself does, but `post_scene_swap` and `poke`
+/// forward to the window's `SceneSwapObserver` (its `Notifier`) so a scene
+/// swap schedules a present exactly like a finished frame does.
+struct WindowSceneBuilderHooks {
+    observer: Box<dyn SceneSwapObserver>,
+}
+
+impl WrSceneBuilderHooks for WindowSceneBuilderHooks {
+    fn register(&self) {}
+    fn pre_scene_build(&self) {}
+    fn pre_scene_swap(&self, _scenebuild_time: u64) {}
+    fn post_scene_swap(
+        &self,
+        document_ids: &Vec<WrDocumentId>,
+        _info: WrSceneSwapResult,
+        _sceneswap_time: u64,
+    ) {
+        self.observer.on_scene_swapped(document_ids);
     }
+    fn post_resource_update(&self, document_ids: &Vec<WrDocumentId>) {
+        self.observer.on_scene_swapped(document_ids);
+    }
+    fn poke(&self) {
+        self.observer.on_scene_swapped(&[]);
+    }
+    fn deregister(&self) {}
 }
 
 #[derive(Debug, Clone)]
@@ -383,6 +626,38 @@ struct ApplicationData {
     fc_cache: LazyFcCache,
     windows: BTreeMap<usize, Window>,
     dwm: Option<DwmFunctions>,
+    /// The first window's context, used as the share-root for every subsequent
+    /// window so textures, buffers and shader programs live in one namespace.
+    shared_context: Option<GlContext>,
+    /// Compiled WebRender shaders, shared across all renderers to avoid
+    /// recompiling the (expensive) shader set once per window, tagged with the
+    /// GL flavor (WGL vs. the EGL/ANGLE fallback) they were compiled for. WGL
+    /// and EGL never share an object namespace, so a cache built for one is
+    /// unusable - and left uninitialized rather than reused - by the other.
+    shader_cache: Option<(GlApiKind, Rc<RefCell<WrShaders>>)>,
+}
+
+impl Drop for ApplicationData {
+    fn drop(&mut self) {
+        // The share-root context must outlive every window that shares its
+        // object namespace (see `create_gl_context`'s `share_with`). The
+        // `BTreeMap` would otherwise drop windows in `HWND` order, which can
+        // free the root while a sharer is still alive. Drop every sharer first,
+        // then the root last.
+        let root_handle = self.shared_context.and_then(|c| c.wgl_handle());
+        let mut windows = mem::take(&mut self.windows);
+        let root = root_handle.and_then(|root| {
+            windows
+                .iter()
+                .find(|(_, w)| w.gl_context.as_ref().and_then(|c| c.wgl_handle()) == Some(root))
+                .map(|(id, _)| *id)
+        });
+        if let Some(root_id) = root {
+            let root_window = windows.remove(&root_id);
+            drop(windows);
+            drop(root_window);
+        }
+    }
 }
 
 // Extra functions from dwmapi.dll
@@ -391,14 +666,26 @@ struct DwmFunctions {
     DwmEnableBlurBehindWindow: Option<extern "system" fn(HWND, &DWM_BLURBEHIND) -> HRESULT>,
     DwmExtendFrameIntoClientArea: Option<extern "system" fn(HWND, &MARGINS) -> HRESULT>,
     DwmDefWindowProc: Option<extern "system" fn(HWND, UINT, WPARAM, LPARAM, *mut LRESULT)>,
+    DwmSetWindowAttribute: Option<extern "system" fn(HWND, u32, *const c_void, u32) -> HRESULT>,
 }
 
+// dwmapi.h window-attribute constants used for dark-mode / non-client styling.
+/// `DWMWA_USE_IMMERSIVE_DARK_MODE` on Windows 10 20H1 (build 18985) and later.
+const DWMWA_USE_IMMERSIVE_DARK_MODE: u32 = 20;
+/// The value the attribute had on Windows 10 builds before 18985.
+const DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1: u32 = 19;
+/// `DWMWA_WINDOW_CORNER_PREFERENCE` (Windows 11): rounded / square corners.
+const DWMWA_WINDOW_CORNER_PREFERENCE: u32 = 33;
+/// `DWMWA_BORDER_COLOR` (Windows 11): custom non-client border color.
+const DWMWA_BORDER_COLOR: u32 = 34;
+
 impl fmt::Debug for DwmFunctions {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         (self._dwmapi_dll_handle as usize).fmt(f)?;
         (self.DwmEnableBlurBehindWindow.map(|f| f as usize)).fmt(f)?;
         (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
-        (self.DwmExtendFrameIntoClientArea.map(|f| f as usize)).fmt(f)?;
+        (self.DwmDefWindowProc.map(|f| f as usize)).fmt(f)?;
+        (self.DwmSetWindowAttribute.map(|f| f as usize)).fmt(f)?;
         Ok(())
     }
 }
@@ -439,13 +726,83 @@ impl DwmFunctions {
             None
         };
 
+        let mut func_name = encode_ascii("DwmSetWindowAttribute");
+        let DwmSetWindowAttribute = unsafe { GetProcAddress(hDwmAPI_DLL, func_name.as_mut_ptr()) };
+        let DwmSetWindowAttribute = if DwmSetWindowAttribute != ptr::null_mut() {
+            Some(unsafe { mem::transmute(DwmSetWindowAttribute) })
+        } else {
+            None
+        };
+
         Some(Self {
             _dwmapi_dll_handle: hDwmAPI_DLL,
             DwmEnableBlurBehindWindow,
             DwmExtendFrameIntoClientArea,
             DwmDefWindowProc,
+            DwmSetWindowAttribute,
         })
     }
+
+    /// Switch the window's non-client area (title bar, borders) between the
+    /// light and dark system styles via `DWMWA_USE_IMMERSIVE_DARK_MODE`. Older
+    /// Windows 10 builds (< 18985) used attribute `19`, so both are set. A
+    /// no-op when `dwmapi.dll` lacks the entry point (Windows 8 and earlier).
+    fn set_dark_mode(&self, hwnd: HWND, dark: bool) {
+        let Some(set_attr) = self.DwmSetWindowAttribute else {
+            return;
+        };
+
+        let value: BOOL = if dark { TRUE } else { FALSE };
+        for attr in [
+            DWMWA_USE_IMMERSIVE_DARK_MODE,
+            DWMWA_USE_IMMERSIVE_DARK_MODE_BEFORE_20H1,
+        ] {
+            unsafe {
+                (set_attr)(
+                    hwnd,
+                    attr,
+                    &value as *const BOOL as *const c_void,
+                    mem::size_of::<BOOL>() as u32,
+                );
+            }
+        }
+    }
+
+    /// Request a `DWMWA_WINDOW_CORNER_PREFERENCE` (Windows 11): `0` default,
+    /// `1` square, `2` round, `3` round-small. A no-op on older systems.
+    /// Exposed for the window-corner option; wired up once `WindowCreateOptions`
+    /// carries the preference.
+    #[allow(dead_code)]
+    fn set_corner_preference(&self, hwnd: HWND, preference: u32) {
+        let Some(set_attr) = self.DwmSetWindowAttribute else {
+            return;
+        };
+        unsafe {
+            (set_attr)(
+                hwnd,
+                DWMWA_WINDOW_CORNER_PREFERENCE,
+                &preference as *const u32 as *const c_void,
+                mem::size_of::<u32>() as u32,
+            );
+        }
+    }
+
+    /// Set a `DWMWA_BORDER_COLOR` (Windows 11) as a `0x00BBGGRR` COLORREF. A
+    /// no-op on older systems. Exposed for the accent-border option.
+    #[allow(dead_code)]
+    fn set_border_color(&self, hwnd: HWND, color: u32) {
+        let Some(set_attr) = self.DwmSetWindowAttribute else {
+            return;
+        };
+        unsafe {
+            (set_attr)(
+                hwnd,
+                DWMWA_BORDER_COLOR,
+                &color as *const u32 as *const c_void,
+                mem::size_of::<u32>() as u32,
+            );
+        }
+    }
 }
 
 impl Drop for DwmFunctions {
@@ -455,10 +812,421 @@ impl Drop for DwmFunctions {
     }
 }
 
+/// Whether the given window theme asks for a dark non-client area.
+fn theme_is_dark(theme: WindowTheme) -> bool {
+    match theme {
+        WindowTheme::DarkMode => true,
+        WindowTheme::LightMode => false,
+    }
+}
+
+/// Read the user's "apps" light / dark preference from the registry. The value
+/// `AppsUseLightTheme` under `…\Themes\Personalize` is a DWORD that is `0` when
+/// the user has selected the dark app theme. Defaults to light when the value
+/// is missing (older Windows versions that predate the setting).
+fn system_prefers_dark_mode() -> bool {
+    use winapi::{
+        shared::minwindef::{DWORD, HKEY},
+        um::{
+            winnt::{KEY_READ, REG_DWORD},
+            winreg::{RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY_CURRENT_USER},
+        },
+    };
+
+    let subkey = encode_wide(
+        "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+    );
+    let value_name = encode_wide("AppsUseLightTheme");
+
+    unsafe {
+        let mut hkey: HKEY = ptr::null_mut();
+        if RegOpenKeyExW(
+            HKEY_CURRENT_USER,
+            subkey.as_ptr(),
+            0,
+            KEY_READ,
+            &mut hkey,
+        ) != 0
+        {
+            return false;
+        }
+
+        let mut data: DWORD = 1;
+        let mut data_size = mem::size_of::<DWORD>() as u32;
+        let mut value_type: DWORD = 0;
+        let result = RegQueryValueExW(
+            hkey,
+            value_name.as_ptr(),
+            ptr::null_mut(),
+            &mut value_type,
+            &mut data as *mut DWORD as *mut u8,
+            &mut data_size,
+        );
+        RegCloseKey(hkey);
+
+        result == 0 && value_type == REG_DWORD && data == 0
+    }
+}
+
 // OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
 struct GlFunctions {
     _opengl32_dll_handle: Option<HINSTANCE>,
     functions: Rc<GenericGlContext>, // implements Rc<dyn gleam::Gl>!
+    /// Detected GL version + extension set, filled in by `load()` once a
+    /// context is current. Empty until then.
+    capabilities: GlCapabilities,
+    /// Per-category views over the flat table, resolved on first access. See
+    /// [`GlFunctions::debug_khr`] and friends.
+    categories: GlCategories,
+}
+
+/// Lazily-initialized category sub-tables, after the grouping Mesa's
+/// `functions.py` emits: rather than scatter a category's pointers across the
+/// flat table, each extension/version group is validated and cached as a unit
+/// on first access, so an unsupported category is a single `None` instead of a
+/// handful of null pointers to check individually.
+#[derive(Default)]
+struct GlCategories {
+    debug_khr: std::cell::OnceCell<Option<DebugKhrFns>>,
+    apple_fence: std::cell::OnceCell<Option<AppleFenceFns>>,
+    ext_marker: std::cell::OnceCell<Option<ExtMarkerFns>>,
+}
+
+/// `GL_KHR_debug` entry points, present as a group or not at all.
+#[derive(Debug)]
+struct DebugKhrFns {
+    message_callback: *mut gl_context_loader::c_void,
+    message_control: *mut gl_context_loader::c_void,
+    get_message_log: *mut gl_context_loader::c_void,
+}
+
+impl DebugKhrFns {
+    /// Builds the group from the resolved table, preferring the core spelling
+    /// and falling back to the `KHR` alias; `None` if the core callback entry
+    /// did not resolve at all.
+    fn from_table(gl: &GenericGlContext) -> Option<Self> {
+        let pick = |core: *mut gl_context_loader::c_void, khr: *mut gl_context_loader::c_void| {
+            if !core.is_null() {
+                core
+            } else {
+                khr
+            }
+        };
+        let message_callback = pick(gl.glDebugMessageCallback, gl.glDebugMessageCallbackKHR);
+        if message_callback.is_null() {
+            return None;
+        }
+        Some(DebugKhrFns {
+            message_callback,
+            message_control: pick(gl.glDebugMessageControl, gl.glDebugMessageControlKHR),
+            get_message_log: pick(gl.glGetDebugMessageLog, gl.glGetDebugMessageLogKHR),
+        })
+    }
+}
+
+/// `GL_APPLE_fence` entry points.
+#[derive(Debug)]
+struct AppleFenceFns {
+    set_fence: *mut gl_context_loader::c_void,
+    test_fence: *mut gl_context_loader::c_void,
+    finish_fence: *mut gl_context_loader::c_void,
+}
+
+impl AppleFenceFns {
+    fn from_table(gl: &GenericGlContext) -> Option<Self> {
+        if gl.glSetFenceAPPLE.is_null() {
+            return None;
+        }
+        Some(AppleFenceFns {
+            set_fence: gl.glSetFenceAPPLE,
+            test_fence: gl.glTestFenceAPPLE,
+            finish_fence: gl.glFinishFenceAPPLE,
+        })
+    }
+}
+
+/// `GL_EXT_debug_marker` / group-marker entry points.
+#[derive(Debug)]
+struct ExtMarkerFns {
+    push_group: *mut gl_context_loader::c_void,
+    pop_group: *mut gl_context_loader::c_void,
+}
+
+impl ExtMarkerFns {
+    fn from_table(gl: &GenericGlContext) -> Option<Self> {
+        if gl.glPopGroupMarkerEXT.is_null() {
+            return None;
+        }
+        Some(ExtMarkerFns {
+            push_group: gl.glPushGroupMarkerEXT,
+            pop_group: gl.glPopGroupMarkerEXT,
+        })
+    }
+}
+
+/// Version / extension capabilities of the loaded dispatch table, modelled on
+/// ANGLE's `DispatchTableGL`: the loader records which `(major, minor)` core
+/// version and which extensions the current context advertises so callers can
+/// branch on capability instead of invoking a null pointer.
+#[derive(Debug, Clone, Default)]
+struct GlCapabilities {
+    version: (u8, u8),
+    extensions: FastBTreeSet<String>,
+}
+
+impl GlCapabilities {
+    /// Queries `GL_VERSION` and the `GL_EXTENSIONS` list of the current context.
+    /// Requires a context to be current.
+    fn detect(gl: &GenericGlContext) -> Self {
+        const GL_VERSION: u32 = 0x1F02;
+        const GL_NUM_EXTENSIONS: u32 = 0x821D;
+
+        const GL_EXTENSIONS: u32 = 0x1F03;
+
+        let version = parse_gl_version(&gl.get_string(GL_VERSION));
+
+        let mut extensions = FastBTreeSet::new();
+        let mut num = [0i32];
+        gl.get_integer_v(GL_NUM_EXTENSIONS, &mut num[..]);
+        for i in 0..num[0].max(0) as u32 {
+            let ext = gl.get_string_i(GL_EXTENSIONS, i);
+            if !ext.is_empty() {
+                extensions.insert(ext);
+            }
+        }
+
+        GlCapabilities { version, extensions }
+    }
+
+    /// `true` if the detected core version is at least `(major, minor)`.
+    fn supports_version(&self, major: u8, minor: u8) -> bool {
+        self.version >= (major, minor)
+    }
+
+    /// `true` if `ext` is advertised by the current context.
+    fn supports(&self, ext: &str) -> bool {
+        self.extensions.contains(ext)
+    }
+
+    /// `true` if `ext` is advertised by the current context. Spelled to read
+    /// naturally at call sites: `gl.capabilities().has("GL_KHR_debug")`.
+    #[allow(dead_code)]
+    fn has(&self, ext: &str) -> bool {
+        self.supports(ext)
+    }
+
+    /// Derives capabilities from *which function pointers actually resolved*,
+    /// independent of the `GL_VERSION`/`GL_EXTENSIONS` strings.
+    ///
+    /// This catches the case where a driver advertises a version string its
+    /// ICD cannot actually back with entry points, and vice-versa. The core
+    /// version is rolled up from the highest band whose representative entry
+    /// point resolved (see [`VERSION_PROBES`]); extensions are inferred from
+    /// the suffixed entries that resolved (see [`EXTENSION_PROBES`]).
+    fn from_resolved(gl: &GenericGlContext) -> Self {
+        let mut version = (1u8, 1u8);
+        for &(probe, ver) in VERSION_PROBES {
+            if !probe(gl).is_null() && ver > version {
+                version = ver;
+            }
+        }
+
+        let mut extensions = FastBTreeSet::new();
+        for &(probe, ext) in EXTENSION_PROBES {
+            if !probe(gl).is_null() {
+                extensions.insert(ext.to_string());
+            }
+        }
+
+        GlCapabilities { version, extensions }
+    }
+
+    /// Folds pointer-derived facts into a string-derived capability set: keep
+    /// the higher reported version and the union of extensions, so a caller is
+    /// never told a feature is present when neither source agrees.
+    fn merge_resolved(&mut self, gl: &GenericGlContext) {
+        let derived = GlCapabilities::from_resolved(gl);
+        if derived.version > self.version {
+            self.version = derived.version;
+        }
+        self.extensions.extend(derived.extensions);
+    }
+}
+
+/// A `(probe, version)` pair: if `probe` finds a non-null pointer, the context
+/// is at least `version`. Keyed by the representative function each GL band
+/// introduced, after Mesa's version→function categorisation.
+type GlProbe = fn(&GenericGlContext) -> *mut gl_context_loader::c_void;
+
+static VERSION_PROBES: &[(GlProbe, (u8, u8))] = &[
+    (|gl| gl.glShaderSource, (2, 0)),
+    (|gl| gl.glUniform4fv, (2, 0)),
+    (|gl| gl.glGenVertexArrays, (3, 0)),
+    (|gl| gl.glMultiDrawElementsBaseVertex, (3, 2)),
+    (|gl| gl.glTexStorage2D, (4, 2)),
+    (|gl| gl.glShaderStorageBlockBinding, (4, 3)),
+];
+
+/// A `(probe, extension)` pair: a resolved suffixed entry point implies the
+/// owning extension is present.
+static EXTENSION_PROBES: &[(GlProbe, &str)] = &[
+    (|gl| gl.glDebugMessageCallbackKHR, "GL_KHR_debug"),
+    (|gl| gl.glSetFenceAPPLE, "GL_APPLE_fence"),
+    (|gl| gl.glPopGroupMarkerEXT, "GL_EXT_debug_marker"),
+];
+
+/// Outcome of walking the whole dispatch table after a load, produced by
+/// [`GlFunctions::validate`]. Grouped so a caller can see at a glance which
+/// core version / extensions are available and how many slots resolved.
+#[derive(Debug, Clone, Default)]
+struct LoadReport {
+    /// Total number of entry-point slots in the table.
+    total: usize,
+    /// Slots that resolved to a real (non-null) pointer.
+    loaded: usize,
+    /// Slots left null by the driver.
+    missing: usize,
+    /// Detected core `(major, minor)` version.
+    version: (u8, u8),
+    /// Extensions advertised by the current context.
+    extensions: FastBTreeSet<String>,
+}
+
+impl LoadReport {
+    /// `true` if the detected core version is at least `(major, minor)`.
+    #[allow(dead_code)]
+    fn supports_version(&self, major: u8, minor: u8) -> bool {
+        self.version >= (major, minor)
+    }
+
+    /// `true` if `ext` is advertised by the current context.
+    #[allow(dead_code)]
+    fn supports(&self, ext: &str) -> bool {
+        self.extensions.contains(ext)
+    }
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "GL {}.{}: {}/{} entry points loaded, {} missing, {} extensions",
+            self.version.0,
+            self.version.1,
+            self.loaded,
+            self.total,
+            self.missing,
+            self.extensions.len(),
+        )
+    }
+}
+
+/// Result of a single [`GlFunctions::self_test`] check.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum SelfTestOutcome {
+    Pass,
+    Fail,
+    /// The feature the check exercises is not available on this context.
+    Skipped,
+}
+
+/// Aggregate diagnostic produced by [`GlFunctions::self_test`].
+#[derive(Debug, Clone)]
+struct SelfTestReport {
+    checks: Vec<(&'static str, SelfTestOutcome)>,
+}
+
+impl SelfTestReport {
+    /// `true` if no check outright failed (skipped checks do not count).
+    #[allow(dead_code)]
+    fn all_passed(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|(_, outcome)| *outcome != SelfTestOutcome::Fail)
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, outcome) in &self.checks {
+            writeln!(f, "  {name}: {outcome:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The GL profile an application targets; selects which entry points are
+/// mandatory in [`GlFunctions::require_profile`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GlProfile {
+    /// Legacy fixed-function + GLSL 1.2 (GL 2.1).
+    Compatibility21,
+    /// Core 3.3 — VAOs, instancing, the modern shader pipeline.
+    Core33,
+    /// Core 4.x — immutable texture storage, compute/SSBO.
+    Core4x,
+}
+
+impl GlProfile {
+    /// The `(name, probe)` pairs that must resolve for this profile. Named so
+    /// the error message can list exactly which symbols are absent.
+    fn mandatory_entries(&self) -> &'static [(&'static str, GlProbe)] {
+        match self {
+            GlProfile::Compatibility21 => &[
+                ("glShaderSource", |gl| gl.glShaderSource),
+                ("glUniform4fv", |gl| gl.glUniform4fv),
+                ("glBindBuffer", |gl| gl.glBindBuffer),
+            ],
+            GlProfile::Core33 => &[
+                ("glShaderSource", |gl| gl.glShaderSource),
+                ("glUniform4fv", |gl| gl.glUniform4fv),
+                ("glBindBuffer", |gl| gl.glBindBuffer),
+                ("glGenVertexArrays", |gl| gl.glGenVertexArrays),
+                ("glBindVertexArray", |gl| gl.glBindVertexArray),
+            ],
+            GlProfile::Core4x => &[
+                ("glShaderSource", |gl| gl.glShaderSource),
+                ("glUniform4fv", |gl| gl.glUniform4fv),
+                ("glGenVertexArrays", |gl| gl.glGenVertexArrays),
+                ("glTexStorage2D", |gl| gl.glTexStorage2D),
+                ("glShaderStorageBlockBinding", |gl| {
+                    gl.glShaderStorageBlockBinding
+                }),
+            ],
+        }
+    }
+}
+
+/// A hard failure loading the dispatch table for a requested [`GlProfile`]:
+/// one or more mandatory entry points did not resolve.
+#[derive(Debug, Clone)]
+struct GlLoadError {
+    profile: GlProfile,
+    missing: Vec<&'static str>,
+}
+
+impl fmt::Display for GlLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "GL context does not satisfy {:?}: missing mandatory functions: {}",
+            self.profile,
+            self.missing.join(", "),
+        )
+    }
+}
+
+/// Parses the leading `"<major>.<minor>"` out of a `GL_VERSION` string such as
+/// `"3.3.0 NVIDIA 560.xx"` or `"OpenGL ES 3.2"`.
+fn parse_gl_version(s: &str) -> (u8, u8) {
+    let digits = s
+        .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .find(|tok| tok.contains('.'))
+        .unwrap_or("");
+    let mut parts = digits.split('.');
+    let major = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    let minor = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    (major, minor)
 }
 
 impl fmt::Debug for GlFunctions {
@@ -479,6 +1247,8 @@ impl GlFunctions {
         Self {
             _opengl32_dll_handle: opengl32_dll,
             functions: Rc::new(context),
+            capabilities: GlCapabilities::default(),
+            categories: GlCategories::default(),
         }
     }
 
@@ -487,17 +1257,53 @@ impl GlFunctions {
         fn get_func(s: &str, opengl32_dll: Option<HINSTANCE>) -> *mut gl_context_loader::c_void {
             use winapi::um::{libloaderapi::GetProcAddress, wingdi::wglGetProcAddress};
 
-            let mut func_name = encode_ascii(s);
-            let addr1 = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
-            (if addr1 != ptr::null_mut() {
-                addr1
-            } else {
+            // `opengl32.dll` only statically exports the GL 1.1 ABI; everything
+            // from GL 1.2 onward and all ARB/EXT/APPLE/KHR entry points must be
+            // resolved against the current context through `wglGetProcAddress`.
+            // So try WGL first and only fall back to the DLL for the 1.1 core.
+            //
+            // `wglGetProcAddress` returns one of 0, 1, 2, 3 or -1 (not just
+            // null) to signal "not found" on some drivers, so treat all of
+            // those sentinels as failure.
+            fn is_wgl_failure(addr: winapi::shared::minwindef::PROC) -> bool {
+                matches!(addr as isize, 0 | 1 | 2 | 3 | -1)
+            }
+
+            // Resolve one exact name through WGL then the DLL, honouring the
+            // `wglGetProcAddress` failure sentinels.
+            fn resolve_exact(
+                name: &str,
+                opengl32_dll: Option<HINSTANCE>,
+            ) -> winapi::shared::minwindef::PROC {
+                let mut func_name = encode_ascii(name);
+                let addr = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+                if !is_wgl_failure(addr) {
+                    return addr;
+                }
                 if let Some(opengl32_dll) = opengl32_dll {
-                    unsafe { GetProcAddress(opengl32_dll, func_name.as_mut_ptr()) }
-                } else {
-                    addr1
+                    let dll_addr = unsafe { GetProcAddress(opengl32_dll, func_name.as_mut_ptr()) };
+                    if !dll_addr.is_null() {
+                        return dll_addr;
+                    }
                 }
-            }) as *mut gl_context_loader::c_void
+                ptr::null_mut()
+            }
+
+            // Many drivers only export the promoted-extension spelling of a
+            // core function (`glGenVertexArraysOES`, `glDrawArraysInstancedARB`,
+            // …). If the bare name misses, retry the usual vendor suffixes
+            // before giving up, mirroring what GLAD/GLEW do.
+            let mut addr = resolve_exact(s, opengl32_dll);
+            if is_wgl_failure(addr) {
+                for suffix in ["ARB", "EXT", "OES", "KHR"] {
+                    let candidate = resolve_exact(&format!("{s}{suffix}"), opengl32_dll);
+                    if !is_wgl_failure(candidate) {
+                        addr = candidate;
+                        break;
+                    }
+                }
+            }
+            addr as *mut gl_context_loader::c_void
         }
 
         self.functions = Rc::new(GenericGlContext {
@@ -1388,10 +2194,668 @@ impl GlFunctions {
             glWindowPos3s: get_func("glWindowPos3s", self._opengl32_dll_handle),
             glWindowPos3sv: get_func("glWindowPos3sv", self._opengl32_dll_handle),
         });
-    }
-}
 
-impl Drop for GlFunctions {
+        // Core-pointer protection: where both a core entry point and a suffixed
+        // extension alias exist (`glGenVertexArrays` vs `glGenVertexArraysAPPLE`,
+        // `glDebugMessageControl` vs `glDebugMessageControlKHR`, …), prefer the
+        // core pointer and only keep the alias when the core slot is null. This
+        // mirrors ANGLE's `if (!FP) FP = ...` guard so an extension alias never
+        // clobbers a valid core pointer.
+        if let Some(table) = Rc::get_mut(&mut self.functions) {
+            macro_rules! prefer_core { ($core:ident, $alias:ident) => {
+                if !table.$core.is_null() {
+                    table.$alias = table.$core;
+                } else if !table.$alias.is_null() {
+                    table.$core = table.$alias;
+                }
+            }}
+
+            prefer_core!(glBindVertexArray, glBindVertexArrayAPPLE);
+            prefer_core!(glGenVertexArrays, glGenVertexArraysAPPLE);
+            prefer_core!(glDeleteVertexArrays, glDeleteVertexArraysAPPLE);
+            prefer_core!(glIsVertexArray, glIsVertexArrayAPPLE);
+            prefer_core!(glDebugMessageCallback, glDebugMessageCallbackKHR);
+            prefer_core!(glDebugMessageControl, glDebugMessageControlKHR);
+            prefer_core!(glDebugMessageInsert, glDebugMessageInsertKHR);
+            prefer_core!(glGetDebugMessageLog, glGetDebugMessageLogKHR);
+        }
+
+        // Record the detected version / extension set so callers can branch on
+        // capability instead of invoking a null pointer, then reconcile it
+        // against which entry points actually resolved.
+        self.capabilities = GlCapabilities::detect(&self.functions);
+        self.capabilities.merge_resolved(&self.functions);
+    }
+
+    /// Detected GL version / extension capabilities (empty before `load()`).
+    #[allow(dead_code)]
+    fn capabilities(&self) -> &GlCapabilities {
+        &self.capabilities
+    }
+
+    /// The `GL_KHR_debug` category, resolved and cached on first access;
+    /// `None` if the context does not expose it.
+    #[allow(dead_code)]
+    fn debug_khr(&self) -> Option<&DebugKhrFns> {
+        self.categories
+            .debug_khr
+            .get_or_init(|| DebugKhrFns::from_table(&self.functions))
+            .as_ref()
+    }
+
+    /// The `GL_APPLE_fence` category; see [`debug_khr`](Self::debug_khr).
+    #[allow(dead_code)]
+    fn apple_fence(&self) -> Option<&AppleFenceFns> {
+        self.categories
+            .apple_fence
+            .get_or_init(|| AppleFenceFns::from_table(&self.functions))
+            .as_ref()
+    }
+
+    /// The `GL_EXT_debug_marker` category; see [`debug_khr`](Self::debug_khr).
+    #[allow(dead_code)]
+    fn ext_marker(&self) -> Option<&ExtMarkerFns> {
+        self.categories
+            .ext_marker
+            .get_or_init(|| ExtMarkerFns::from_table(&self.functions))
+            .as_ref()
+    }
+
+    /// A fully-null dispatch table with no backing driver, for headless and
+    /// unit-test builds where no GL context is available.
+    ///
+    /// Every entry point is null; downstream code that only constructs render
+    /// state (building display lists, sizing glyph atlases, …) can be exercised
+    /// without a driver present. Pair with [`GlFunctions::with_null_fallback`]
+    /// if the code under test actually *calls* through the table.
+    #[allow(dead_code)]
+    fn null() -> Self {
+        // `mem::zeroed` gives an all-null `GenericGlContext`; we deliberately
+        // skip `load_dll` so this works on a machine without `opengl32.dll`.
+        let context: GenericGlContext = unsafe { mem::zeroed() };
+        Self {
+            _opengl32_dll_handle: None,
+            functions: Rc::new(context),
+            capabilities: GlCapabilities::default(),
+            categories: GlCategories::default(),
+        }
+    }
+
+    /// Loads the table like [`GlFunctions::load`], then, on 64-bit targets,
+    /// redirects every entry point that failed to resolve to a shared no-op
+    /// stub instead of leaving it null. On 32-bit targets the unresolved slots
+    /// stay null (see [`GlFunctions::patch_null_pointers`]).
+    ///
+    /// This mirrors ANGLE's `ANGLE_ENABLE_OPENGL_NULL` path: calling an
+    /// unsupported function returns instead of jumping through a null pointer
+    /// and crashing; debug builds log `called unsupported GL function` once
+    /// per process. Used by builds
+    /// that would rather degrade than abort when the driver is missing an
+    /// entry point.
+    #[allow(dead_code)]
+    fn with_null_fallback(&mut self) {
+        self.load();
+        self.patch_null_pointers();
+    }
+
+    /// Walks the whole dispatch table after [`load`](Self::load) and reports
+    /// how many entry points resolved, alongside the detected core version and
+    /// extension set.
+    ///
+    /// Modelled on Mesa's `check_table.cpp`, this lets an application fail fast
+    /// with a clear message ("this build needs GL 3.3, the context is 2.1")
+    /// instead of discovering a null pointer mid-render.
+    #[allow(dead_code)]
+    fn validate(&self) -> LoadReport {
+        let ptr_size = mem::size_of::<*mut gl_context_loader::c_void>();
+        let total = mem::size_of::<GenericGlContext>() / ptr_size;
+
+        // SAFETY: see `patch_null_pointers` — the table is a homogeneous
+        // `#[repr(C)]` array of `*mut c_void`.
+        let slots = unsafe {
+            std::slice::from_raw_parts(
+                Rc::as_ptr(&self.functions) as *const *mut gl_context_loader::c_void,
+                total,
+            )
+        };
+        let loaded = slots.iter().filter(|p| !p.is_null()).count();
+
+        LoadReport {
+            total,
+            loaded,
+            missing: total - loaded,
+            version: self.capabilities.version,
+            extensions: self.capabilities.extensions.clone(),
+        }
+    }
+
+    /// Cheap lookup for a single extension; see [`GlCapabilities::supports`].
+    #[allow(dead_code)]
+    fn supports(&self, ext: &str) -> bool {
+        self.capabilities.supports(ext)
+    }
+
+    /// Exercises a representative subset of the resolved pointers against the
+    /// current context and checks observable results, after the model of
+    /// Mesa's `getprocaddress.c`.
+    ///
+    /// Null-checking alone cannot tell a working ICD from one where
+    /// `wglGetProcAddress` returned a non-null stub; actually compiling a
+    /// trivial shader and allocating a texture does. Each check is
+    /// pass/fail/skipped-when-unavailable; the aggregate is a diagnostic.
+    #[allow(dead_code)]
+    fn self_test(&self) -> SelfTestReport {
+        use gl_context_loader::gl;
+
+        let gl_ctx = &*self.functions;
+        let mut checks = Vec::new();
+
+        // 1. Compile a trivial vertex shader and read back its compile status.
+        checks.push(("compile_trivial_shader", {
+            if self.functions.glShaderSource.is_null() {
+                SelfTestOutcome::Skipped
+            } else {
+                let shader = gl_ctx.create_shader(gl::VERTEX_SHADER);
+                gl_ctx.shader_source(shader, &[b"void main(){ gl_Position = vec4(0.0); }"]);
+                gl_ctx.compile_shader(shader);
+                let mut status = [0i32];
+                gl_ctx.get_shader_iv(shader, gl::COMPILE_STATUS, &mut status[..]);
+                gl_ctx.delete_shader(shader);
+                if status[0] != 0 {
+                    SelfTestOutcome::Pass
+                } else {
+                    SelfTestOutcome::Fail
+                }
+            }
+        }));
+
+        // 2. Allocate immutable texture storage and read a level parameter.
+        checks.push(("tex_storage_2d", {
+            if !self.capabilities.supports_version(4, 2) {
+                SelfTestOutcome::Skipped
+            } else {
+                let tex = gl_ctx.gen_textures(1);
+                gl_ctx.bind_texture(gl::TEXTURE_2D, tex[0]);
+                gl_ctx.tex_storage_2d(gl::TEXTURE_2D, 1, gl::RGBA8, 4, 4);
+                let err = gl_ctx.get_error();
+                gl_ctx.delete_textures(&tex);
+                if err == gl::NO_ERROR {
+                    SelfTestOutcome::Pass
+                } else {
+                    SelfTestOutcome::Fail
+                }
+            }
+        }));
+
+        SelfTestReport { checks }
+    }
+
+    /// Checks that every entry point mandatory for `profile` resolved, after a
+    /// [`load`](Self::load). Returns `Err` naming the missing symbols so a
+    /// startup failure reads `GL context does not satisfy Core33: missing
+    /// mandatory functions: glShaderSource, glUniform4fv` instead of
+    /// segfaulting on the first null call.
+    #[allow(dead_code)]
+    fn require_profile(&self, profile: GlProfile) -> Result<(), GlLoadError> {
+        let missing: Vec<&'static str> = profile
+            .mandatory_entries()
+            .iter()
+            .filter(|(_, probe)| probe(&self.functions).is_null())
+            .map(|(name, _)| *name)
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(GlLoadError { profile, missing })
+        }
+    }
+
+    /// Points every null slot in the table at [`gl_unsupported_stub`] — on
+    /// 64-bit targets only; see that function's doc comment for why 32-bit
+    /// leaves the slots null instead.
+    ///
+    /// The `GenericGlContext` is `#[repr(C)]` and homogeneous — every field is
+    /// a `*mut c_void` function pointer — so it can be treated as a flat slice
+    /// of pointers and patched without naming each of the ~hundreds of fields.
+    fn patch_null_pointers(&mut self) {
+        let table = match Rc::get_mut(&mut self.functions) {
+            Some(table) => table,
+            // Another `Rc` is live (a renderer already holds the table); in
+            // that case it is already in use and not safe to re-point.
+            None => return,
+        };
+
+        let ptr_size = mem::size_of::<*mut gl_context_loader::c_void>();
+        let count = mem::size_of::<GenericGlContext>() / ptr_size;
+        debug_assert_eq!(mem::size_of::<GenericGlContext>() % ptr_size, 0);
+
+        // SAFETY: `GenericGlContext` is a `#[repr(C)]` struct whose every field
+        // is a `*mut c_void`, so its layout is exactly `[*mut c_void; count]`.
+        let slots = unsafe {
+            std::slice::from_raw_parts_mut(
+                table as *mut GenericGlContext as *mut *mut gl_context_loader::c_void,
+                count,
+            )
+        };
+
+        // Only sound on 64-bit: Win64 has one calling convention for every
+        // signature (the caller always cleans the stack), so a 0-arg,
+        // 0-return stub can stand in for an N-arg GL entry point for free.
+        // On 32-bit `stdcall` the callee pops its own arguments, so invoking
+        // a 0-arg stub through an N-arg pointer pops 0 bytes against however
+        // many the caller pushed — corrupting the stack — and any
+        // value-returning call reads garbage out of `eax`. There is no
+        // single stub signature that is safe for every slot without knowing
+        // each field's real arity, which `GenericGlContext` does not expose
+        // to us here, so on 32-bit we leave unresolved slots null: calling
+        // one still crashes, but as an honest null-pointer fault instead of
+        // a silently corrupted stack.
+        #[cfg(target_pointer_width = "64")]
+        {
+            let stub = gl_unsupported_stub as *mut gl_context_loader::c_void;
+            for slot in slots {
+                if slot.is_null() {
+                    *slot = stub;
+                }
+            }
+        }
+        #[cfg(not(target_pointer_width = "64"))]
+        {
+            let _ = slots;
+        }
+    }
+}
+
+/// Shared no-op stub that unresolved entry points are pointed at by
+/// [`GlFunctions::patch_null_pointers`] — 64-bit targets only.
+///
+/// It takes no arguments and returns nothing; that is a harmless stand-in for
+/// any GL signature only because Win64 uses a single calling convention
+/// where the caller (not the callee) cleans the stack. On 32-bit `stdcall`
+/// this stub is unsound (see `patch_null_pointers`), so it is never wired in
+/// there.
+extern "system" fn gl_unsupported_stub() {
+    // Only log once per process to avoid drowning the log when a hot path hits
+    // a missing entry point every frame.
+    use std::sync::atomic::{AtomicBool, Ordering};
+    static WARNED: AtomicBool = AtomicBool::new(false);
+    if !WARNED.swap(true, Ordering::Relaxed) {
+        #[cfg(debug_assertions)]
+        eprintln!("azul: called unsupported GL function (stubbed no-op)");
+    }
+}
+
+/// Optional GL call-stream capture, in the spirit of apitrace: a layer that
+/// records every call with its arguments before delegating to the real
+/// pointer, for diagnosing mis-rendered frames and producing minimal repros.
+///
+/// Gated behind the `gl_trace` feature so release builds pay nothing.
+#[cfg(feature = "gl_trace")]
+pub mod gl_trace {
+    use super::GenericGlContext;
+    use std::rc::Rc;
+    use std::sync::Mutex;
+
+    /// Coarse category of a captured call, used by [`TraceFilter`] so users can
+    /// restrict capture to the calls they care about.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum CallCategory {
+        Draw,
+        Buffer,
+        Shader,
+        Texture,
+        State,
+        Other,
+    }
+
+    /// A single captured call: the entry-point name, its serialized arguments,
+    /// the return value (if any), and provenance.
+    #[derive(Debug, Clone)]
+    pub struct TracedCall {
+        pub function: &'static str,
+        pub category: CallCategory,
+        pub args: String,
+        pub ret: Option<String>,
+        pub thread: u64,
+        /// Monotonic call index; cheaper and more portable than a wall clock.
+        pub seq: u64,
+    }
+
+    /// Where captured calls are written. Implemented by an in-memory ring
+    /// buffer by default; a binary trace file can be dropped in behind the same
+    /// trait.
+    pub trait TraceSink {
+        fn record(&self, call: TracedCall);
+    }
+
+    /// A bounded in-memory ring buffer of the most recent calls.
+    #[derive(Debug)]
+    pub struct RingBufferSink {
+        inner: Mutex<(Vec<TracedCall>, usize)>,
+        capacity: usize,
+    }
+
+    impl RingBufferSink {
+        pub fn new(capacity: usize) -> Self {
+            RingBufferSink {
+                inner: Mutex::new((Vec::with_capacity(capacity), 0)),
+                capacity: capacity.max(1),
+            }
+        }
+
+        /// Snapshot of the buffered calls in capture order.
+        pub fn drain(&self) -> Vec<TracedCall> {
+            let mut guard = self.inner.lock().unwrap();
+            let (buf, head) = &mut *guard;
+            let (a, b) = buf.split_at(*head);
+            let ordered = b.iter().chain(a.iter()).cloned().collect();
+            buf.clear();
+            *head = 0;
+            ordered
+        }
+    }
+
+    impl TraceSink for RingBufferSink {
+        fn record(&self, call: TracedCall) {
+            let mut guard = self.inner.lock().unwrap();
+            let (buf, head) = &mut *guard;
+            if buf.len() < self.capacity {
+                buf.push(call);
+            } else {
+                buf[*head] = call;
+                *head = (*head + 1) % self.capacity;
+            }
+        }
+    }
+
+    /// Restricts capture to a set of [`CallCategory`] values.
+    #[derive(Debug, Clone)]
+    pub struct TraceFilter {
+        allowed: Vec<CallCategory>,
+    }
+
+    impl TraceFilter {
+        /// Capture everything.
+        pub fn all() -> Self {
+            TraceFilter { allowed: Vec::new() }
+        }
+
+        /// Capture only the listed categories.
+        pub fn only(categories: &[CallCategory]) -> Self {
+            TraceFilter { allowed: categories.to_vec() }
+        }
+
+        fn admits(&self, category: CallCategory) -> bool {
+            self.allowed.is_empty() || self.allowed.contains(&category)
+        }
+    }
+
+    /// Wrapping layer that holds the resolved table and records calls that pass
+    /// the filter before delegating to the real entry point.
+    pub struct GlTrace<S: TraceSink> {
+        functions: Rc<GenericGlContext>,
+        sink: S,
+        filter: TraceFilter,
+        seq: std::cell::Cell<u64>,
+    }
+
+    impl<S: TraceSink> GlTrace<S> {
+        pub fn new(functions: Rc<GenericGlContext>, sink: S, filter: TraceFilter) -> Self {
+            GlTrace {
+                functions,
+                sink,
+                filter,
+                seq: std::cell::Cell::new(0),
+            }
+        }
+
+        /// The wrapped table, for passing on to code that renders through it.
+        pub fn inner(&self) -> &Rc<GenericGlContext> {
+            &self.functions
+        }
+
+        /// Records one call if its `category` is admitted by the filter. Call
+        /// this from each wrapped entry point immediately before delegating.
+        pub fn capture(
+            &self,
+            function: &'static str,
+            category: CallCategory,
+            args: String,
+            ret: Option<String>,
+        ) {
+            if !self.filter.admits(category) {
+                return;
+            }
+            let seq = self.seq.get();
+            self.seq.set(seq + 1);
+            self.sink.record(TracedCall {
+                function,
+                category,
+                args,
+                ret,
+                thread: thread_id(),
+                seq,
+            });
+        }
+    }
+
+    /// A stable-per-thread id without pulling in a clock; good enough to
+    /// disentangle calls from a multi-threaded renderer in the trace.
+    fn thread_id() -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        std::thread::current().id().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Safe Rust wrapper around the `KHR_debug` / GL 4.3 debug-message entry
+/// points, which the table resolves but only exposes as raw pointers.
+///
+/// [`DebugMessageCallback`] installs a Rust closure as the driver's debug
+/// callback, handling the `extern "system"` trampoline and the `user_param`
+/// round-trip, and transparently picks the core or `KHR`-suffixed entry point
+/// depending on which one resolved.
+mod gl_debug {
+    use super::GenericGlContext;
+    use std::os::raw::{c_char, c_void};
+    use std::rc::Rc;
+
+    type DebugProc = extern "system" fn(
+        source: u32,
+        gltype: u32,
+        id: u32,
+        severity: u32,
+        length: i32,
+        message: *const c_char,
+        user_param: *mut c_void,
+    );
+    type SetCallbackFn = extern "system" fn(DebugProc, *mut c_void);
+    type ControlFn = extern "system" fn(u32, u32, u32, i32, *const u32, u8);
+    type GetLogFn = extern "system" fn(
+        u32,
+        i32,
+        *mut u32,
+        *mut u32,
+        *mut u32,
+        *mut u32,
+        *mut i32,
+        *mut c_char,
+    ) -> u32;
+
+    /// Raw fields are all null-typed; reinterpret one as the given fn pointer,
+    /// preferring the core entry point and falling back to the `KHR` alias.
+    unsafe fn pick<T: Copy>(core: *mut c_void, khr: *mut c_void) -> Option<T> {
+        let chosen = if !core.is_null() { core } else { khr };
+        if chosen.is_null() {
+            None
+        } else {
+            Some(*(&chosen as *const *mut c_void as *const T))
+        }
+    }
+
+    /// A decoded debug message handed to the user's closure.
+    pub struct DebugMessage<'a> {
+        pub source: u32,
+        pub gltype: u32,
+        pub id: u32,
+        pub severity: u32,
+        pub message: &'a str,
+    }
+
+    /// Owns a boxed debug closure and keeps it alive for as long as it is
+    /// installed as the driver's callback.
+    pub struct DebugMessageCallback {
+        functions: Rc<GenericGlContext>,
+        // Boxed twice: the outer box gives a stable `*mut c_void` to hand the
+        // driver as `user_param`; the inner trait object is the user closure.
+        _closure: Box<Box<dyn FnMut(DebugMessage)>>,
+    }
+
+    impl DebugMessageCallback {
+        /// Installs `closure` as the context's debug-message callback. Returns
+        /// `None` if neither the core nor the `KHR` entry point is available.
+        pub fn install(
+            functions: Rc<GenericGlContext>,
+            closure: impl FnMut(DebugMessage) + 'static,
+        ) -> Option<Self> {
+            let set: SetCallbackFn = unsafe {
+                pick(
+                    functions.glDebugMessageCallback,
+                    functions.glDebugMessageCallbackKHR,
+                )?
+            };
+
+            let boxed: Box<Box<dyn FnMut(DebugMessage)>> = Box::new(Box::new(closure));
+            let user_param = &*boxed as *const Box<dyn FnMut(DebugMessage)> as *mut c_void;
+            set(trampoline, user_param);
+
+            Some(DebugMessageCallback {
+                functions,
+                _closure: boxed,
+            })
+        }
+
+        /// Enables or disables messages matching `(source, gltype, severity)`
+        /// via `glDebugMessageControl`. Pass `0x1100` (`GL_DONT_CARE`) to wildcard a
+        /// field.
+        pub fn set_enabled(&self, source: u32, gltype: u32, severity: u32, enabled: bool) {
+            if let Some(control) = unsafe {
+                pick::<ControlFn>(
+                    self.functions.glDebugMessageControl,
+                    self.functions.glDebugMessageControlKHR,
+                )
+            } {
+                control(
+                    source,
+                    gltype,
+                    severity,
+                    0,
+                    std::ptr::null(),
+                    enabled as u8,
+                );
+            }
+        }
+
+        /// Drains messages the driver buffered instead of delivering through
+        /// the callback, via `glGetDebugMessageLog`. Returns the decoded
+        /// messages, newest last.
+        pub fn drain_debug_log(&self, max: usize) -> Vec<(u32, u32, u32, u32, String)> {
+            let get: GetLogFn = match unsafe {
+                pick(
+                    self.functions.glGetDebugMessageLog,
+                    self.functions.glGetDebugMessageLogKHR,
+                )
+            } {
+                Some(f) => f,
+                None => return Vec::new(),
+            };
+
+            let mut out = Vec::new();
+            let mut sources = vec![0u32; max];
+            let mut types = vec![0u32; max];
+            let mut ids = vec![0u32; max];
+            let mut severities = vec![0u32; max];
+            let mut lengths = vec![0i32; max];
+            let mut text = vec![0i8; max * 256];
+
+            let count = get(
+                max as u32,
+                text.len() as i32,
+                sources.as_mut_ptr(),
+                types.as_mut_ptr(),
+                ids.as_mut_ptr(),
+                severities.as_mut_ptr(),
+                lengths.as_mut_ptr(),
+                text.as_mut_ptr(),
+            );
+
+            let mut offset = 0usize;
+            for i in 0..count as usize {
+                let len = lengths[i].max(0) as usize;
+                let bytes = &text[offset..offset + len];
+                let message = String::from_utf8_lossy(
+                    &bytes.iter().map(|b| *b as u8).collect::<Vec<_>>(),
+                )
+                .trim_end_matches('\0')
+                .to_string();
+                out.push((sources[i], types[i], ids[i], severities[i], message));
+                offset += len;
+            }
+            out
+        }
+    }
+
+    impl Drop for DebugMessageCallback {
+        fn drop(&mut self) {
+            // Detach before the closure box is freed so the driver cannot call
+            // into a dangling pointer.
+            if let Some(set) = unsafe {
+                pick::<SetCallbackFn>(
+                    self.functions.glDebugMessageCallback,
+                    self.functions.glDebugMessageCallbackKHR,
+                )
+            } {
+                set(trampoline, std::ptr::null_mut());
+            }
+        }
+    }
+
+    /// `extern "system"` shim the driver calls; recovers the boxed closure from
+    /// `user_param` and forwards a decoded [`DebugMessage`].
+    extern "system" fn trampoline(
+        source: u32,
+        gltype: u32,
+        id: u32,
+        severity: u32,
+        length: i32,
+        message: *const c_char,
+        user_param: *mut c_void,
+    ) {
+        if user_param.is_null() || message.is_null() {
+            return;
+        }
+        // SAFETY: `user_param` is the `&*boxed` pointer handed to the driver in
+        // `install`; the box outlives the callback installation.
+        let closure = unsafe { &mut *(user_param as *mut Box<dyn FnMut(DebugMessage)>) };
+        let text = unsafe {
+            let len = if length < 0 { 0 } else { length as usize };
+            let bytes = std::slice::from_raw_parts(message as *const u8, len);
+            std::str::from_utf8(bytes).unwrap_or("")
+        };
+        closure(DebugMessage {
+            source,
+            gltype,
+            id,
+            severity,
+            message: text,
+        });
+    }
+}
+
+impl Drop for GlFunctions {
     fn drop(&mut self) {
         use winapi::um::libloaderapi::FreeLibrary;
         if let Some(opengl32) = self._opengl32_dll_handle {
@@ -1407,6 +2871,11 @@ struct ExtraWglFunctions {
     wglCreateContextAttribsARB: Option<extern "system" fn(HDC, HGLRC, *const [i32]) -> HGLRC>,
     wglSwapIntervalEXT: Option<extern "system" fn(i32) -> i32>,
     wglChoosePixelFormatARB: Option<extern "system" fn(HDC, *const [i32], *const f32, u32, *mut i32, *mut u32) -> BOOL>,
+    wglGetPixelFormatAttribivARB: Option<extern "system" fn(HDC, i32, i32, u32, *const i32, *mut i32) -> BOOL>,
+    wglGetSwapIntervalEXT: Option<extern "system" fn() -> i32>,
+    /// `true` if `WGL_EXT_swap_control_tear` is present, i.e. adaptive vsync
+    /// (swap interval `-1`) is supported.
+    swap_control_tear: bool,
 }
 
 impl fmt::Debug for ExtraWglFunctions {
@@ -1414,6 +2883,9 @@ impl fmt::Debug for ExtraWglFunctions {
         self.wglCreateContextAttribsARB.map(|f| f as usize).fmt(f)?;
         self.wglSwapIntervalEXT.map(|f| f as usize).fmt(f)?;
         self.wglChoosePixelFormatARB.map(|f| f as usize).fmt(f)?;
+        self.wglGetPixelFormatAttribivARB.map(|f| f as usize).fmt(f)?;
+        self.wglGetSwapIntervalEXT.map(|f| f as usize).fmt(f)?;
+        self.swap_control_tear.fmt(f)?;
         Ok(())
     }
 }
@@ -1459,102 +2931,727 @@ impl ExtraWglFunctions {
             Some(unsafe { mem::transmute(proc_address) })
         };
 
+        let mut func_name = encode_ascii("wglGetPixelFormatAttribivARB");
+        let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+        extra.wglGetPixelFormatAttribivARB = if proc_address == ptr::null_mut() {
+            None
+        } else {
+            Some(unsafe { mem::transmute(proc_address) })
+        };
+
+        let mut func_name = encode_ascii("wglGetSwapIntervalEXT");
+        let proc_address = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+        extra.wglGetSwapIntervalEXT = if proc_address == ptr::null_mut() {
+            None
+        } else {
+            Some(unsafe { mem::transmute(proc_address) })
+        };
+
+        // WGL_EXT_swap_control_tear enables adaptive vsync (interval -1).
+        extra.swap_control_tear = wgl_extension_supported("WGL_EXT_swap_control_tear");
+
         extra
     }
 }
 
-struct Window {
-    /// HWND handle of the plaform window
-    hwnd: HWND,
-    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
-    internal: WindowInternal,
-    /// OpenGL context handle - None if running in software mode
-    gl_context: Option<HGLRC>,
-    /// OpenGL functions for faster rendering
-    gl_functions: GlFunctions,
-    /// OpenGL context pointer with compiled SVG and FXAA shaders
-    gl_context_ptr: OptionGlContextPtr,
-    /// Main render API that can be used to register and un-register fonts and images
-    render_api: WrRenderApi,
-    /// WebRender renderer implementation (software or hardware)
-    renderer: Option<WrRenderer>,
-    /// Hit-tester, lazily initialized and updated every time the display list changes layout
-    hit_tester: AsyncHitTester,
-    /// ID -> Callback map for the window menu (default: empty map)
-    menu_callbacks: BTreeMap<u16, MenuCallback>,
-    /// Timer ID -> Win32 timer map
-    timers: BTreeMap<TimerId, TIMERPTR>,
-    /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
-    thread_timer_running: Option<TIMERPTR>,
-    /// Hash of the current system menu
-    menu_hash: Option<u64>,
+/// Returns `true` if `ext` appears in the `WGL_EXTENSIONS_ARB` string of the
+/// current device context. Requires a context to be current.
+fn wgl_extension_supported(ext: &str) -> bool {
+    use winapi::um::wingdi::{wglGetCurrentDC, wglGetProcAddress};
+
+    let mut func_name = encode_ascii("wglGetExtensionsStringARB");
+    let proc = unsafe { wglGetProcAddress(func_name.as_mut_ptr()) };
+    if proc.is_null() {
+        return false;
+    }
+
+    let get_ext: extern "system" fn(HDC) -> *const i8 = unsafe { mem::transmute(proc) };
+    let hdc = unsafe { wglGetCurrentDC() };
+    let ptr = get_ext(hdc);
+    if ptr.is_null() {
+        return false;
+    }
+
+    let cstr = unsafe { std::ffi::CStr::from_ptr(ptr) };
+    cstr.to_str()
+        .map(|s| s.split(' ').any(|tok| tok == ext))
+        .unwrap_or(false)
 }
 
-impl fmt::Debug for Window {
+/// EGL entry points resolved from ANGLE's `libEGL.dll`. Only the handful of
+/// calls needed to create a context, make it current and present it are loaded;
+/// the rest of the GL API is dispatched through the normal `GlFunctions` table.
+#[derive(Copy, Clone)]
+struct EglFunctions {
+    eglGetDisplay: extern "system" fn(*mut gl_context_loader::c_void) -> *mut gl_context_loader::c_void,
+    eglInitialize: extern "system" fn(*mut gl_context_loader::c_void, *mut i32, *mut i32) -> u32,
+    eglBindAPI: extern "system" fn(u32) -> u32,
+    eglChooseConfig: extern "system" fn(*mut gl_context_loader::c_void, *const i32, *mut *mut gl_context_loader::c_void, i32, *mut i32) -> u32,
+    eglCreateContext: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void, *mut gl_context_loader::c_void, *const i32) -> *mut gl_context_loader::c_void,
+    eglCreateWindowSurface: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void, HWND, *const i32) -> *mut gl_context_loader::c_void,
+    eglMakeCurrent: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void, *mut gl_context_loader::c_void, *mut gl_context_loader::c_void) -> u32,
+    eglSwapBuffers: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void) -> u32,
+    eglDestroyContext: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void) -> u32,
+    eglDestroySurface: extern "system" fn(*mut gl_context_loader::c_void, *mut gl_context_loader::c_void) -> u32,
+    eglTerminate: extern "system" fn(*mut gl_context_loader::c_void) -> u32,
+}
+
+impl fmt::Debug for EglFunctions {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.hwnd.fmt(f)?;
-        self.internal.fmt(f)?;
-        self.gl_context.fmt(f)?;
-        self.gl_context_ptr.fmt(f)?;
-        self.renderer.is_some().fmt(f)?;
-        self.menu_callbacks.fmt(f)?;
-        self.menu_hash.fmt(f)?;
+        // `extern "system" fn` pointers don't implement `Debug`, so print their
+        // addresses like `ExtraWglFunctions` does.
+        (self.eglGetDisplay as usize).fmt(f)?;
+        (self.eglInitialize as usize).fmt(f)?;
+        (self.eglBindAPI as usize).fmt(f)?;
+        (self.eglChooseConfig as usize).fmt(f)?;
+        (self.eglCreateContext as usize).fmt(f)?;
+        (self.eglCreateWindowSurface as usize).fmt(f)?;
+        (self.eglMakeCurrent as usize).fmt(f)?;
+        (self.eglSwapBuffers as usize).fmt(f)?;
+        (self.eglDestroyContext as usize).fmt(f)?;
+        (self.eglDestroySurface as usize).fmt(f)?;
+        (self.eglTerminate as usize).fmt(f)?;
         Ok(())
     }
 }
 
-impl Drop for Window {
-    fn drop(&mut self) {
-        use winapi::um::wingdi::{wglMakeCurrent, wglDeleteContext};
-        unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
-        if let Some(context) = self.gl_context.as_mut() {
-            unsafe { wglDeleteContext(*context); }
+/// An initialized ANGLE display together with the context and window surface
+/// created on it. Handles are raw EGL pointers, so the whole struct is `Copy`
+/// and lives inline on the window just like the `HGLRC` it replaces.
+#[derive(Copy, Clone, Debug)]
+struct EglContext {
+    display: *mut gl_context_loader::c_void,
+    surface: *mut gl_context_loader::c_void,
+    context: *mut gl_context_loader::c_void,
+    egl: EglFunctions,
+}
+
+/// Which GL entry-point family a `GlContext` was created through. WGL and the
+/// EGL/ANGLE fallback draw their shaders from incompatible compilers and never
+/// share an object namespace, so a `WrShaders` cache is only reusable across
+/// windows created through the same flavor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum GlApiKind {
+    Wgl,
+    Egl,
+}
+
+/// A created GL rendering context plus the entry points needed to make it
+/// current and present it. `Wgl` is the native desktop path; `Egl` is the ANGLE
+/// fallback used when no usable desktop ICD is present (software OpenGL, VM or
+/// RDP sessions). Mirrors glutin's `enum Context { Wgl(..), Egl(..) }`.
+#[derive(Copy, Clone, Debug)]
+enum GlContext {
+    Wgl(HGLRC),
+    Egl(EglContext),
+}
+
+impl GlContext {
+    /// Which entry-point family this context was created through; used to key
+    /// the shared shader cache so a WGL cache is never handed to an EGL
+    /// renderer or vice versa.
+    fn api_kind(&self) -> GlApiKind {
+        match self {
+            GlContext::Wgl(_) => GlApiKind::Wgl,
+            GlContext::Egl(_) => GlApiKind::Egl,
         }
-        if let Some(renderer) = self.renderer.take() {
-            renderer.deinit();
+    }
+
+    /// The underlying `HGLRC` for a WGL context, used as the share-root for
+    /// `wglShareLists`. `None` for EGL contexts, which share through a common
+    /// display instead.
+    fn wgl_handle(&self) -> Option<HGLRC> {
+        match self {
+            GlContext::Wgl(hrc) => Some(*hrc),
+            GlContext::Egl(_) => None,
         }
     }
-}
 
-impl Window {
+    /// Bind this context to `hdc` (ignored for EGL, which binds to its own
+    /// window surface) as the current rendering target of the calling thread.
+    /// Returns `false` if the driver rejected the call, which on a real
+    /// context (as opposed to one that was never valid) means the context was
+    /// lost - see [`Window::recover_lost_gl_context`].
+    fn make_current(&self, hdc: HDC) -> bool {
+        match self {
+            GlContext::Wgl(hrc) => unsafe {
+                winapi::um::wingdi::wglMakeCurrent(hdc, *hrc) != 0
+            },
+            GlContext::Egl(ctx) => {
+                (ctx.egl.eglMakeCurrent)(ctx.display, ctx.surface, ctx.surface, ctx.context) != 0
+            }
+        }
+    }
 
-    fn get_id(&self) -> usize {
-        self.hwnd as usize
+    /// Detach any context from the calling thread.
+    fn release_current(&self) {
+        match self {
+            GlContext::Wgl(_) => unsafe {
+                winapi::um::wingdi::wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
+            },
+            GlContext::Egl(ctx) => {
+                (ctx.egl.eglMakeCurrent)(
+                    ctx.display,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                );
+            }
+        }
     }
 
-    // Creates a new HWND according to the options
-    fn create(
-        hinstance: HINSTANCE,
-        mut options: WindowCreateOptions,
-        data: SharedApplicationData,
-    ) -> Result<Self, WindowsWindowCreateError> {
+    /// Present the back buffer. WGL swaps the device context, EGL its window
+    /// surface. Returns `false` on failure, which most commonly means the
+    /// context was lost (GPU reset, driver update, dGPU/iGPU switch, display
+    /// hotplug) - see [`Window::recover_lost_gl_context`].
+    fn swap_buffers(&self, hdc: HDC) -> bool {
+        match self {
+            GlContext::Wgl(_) => unsafe { winapi::um::wingdi::SwapBuffers(hdc) != 0 },
+            GlContext::Egl(ctx) => (ctx.egl.eglSwapBuffers)(ctx.display, ctx.surface) != 0,
+        }
+    }
 
-        use crate::{
-            compositor::Compositor,
-            wr_translate::{
-                translate_document_id_wr, translate_id_namespace_wr, wr_translate_debug_flags,
-                wr_translate_document_id,
-            },
-        };
-        use azul_core::{
-            callbacks::PipelineId,
-            gl::GlContextPtr,
-            window::{
-                CursorPosition, HwAcceleration,
-                LogicalPosition, ScrollResult,
-                PhysicalSize, RendererType,
-                WindowInternalInit, FullHitTest,
-                WindowFrame,
+    /// Tear down the context. WGL deletes the `HGLRC`; EGL destroys the surface
+    /// and context and terminates the display.
+    fn delete(&self) {
+        match self {
+            GlContext::Wgl(hrc) => unsafe {
+                winapi::um::wingdi::wglDeleteContext(*hrc);
             },
-        };
-        use webrender::api::ColorF as WrColorF;
-        use webrender::ProgramCache as WrProgramCache;
-        use winapi::{
+            GlContext::Egl(ctx) => {
+                (ctx.egl.eglDestroySurface)(ctx.display, ctx.surface);
+                (ctx.egl.eglDestroyContext)(ctx.display, ctx.context);
+                (ctx.egl.eglTerminate)(ctx.display);
+            }
+        }
+    }
+}
+
+/// A minimal COM `IDropTarget` implementation that accepts dropped files.
+///
+/// The object is heap-allocated and registered with `RegisterDragDrop`; Windows
+/// calls the vtable thunks below on the event-loop (STA) thread. `lpVtbl` must
+/// stay the first field so a `*mut DropTarget` is layout-compatible with a
+/// `*mut IDropTarget`.
+#[repr(C)]
+struct DropTarget {
+    lpVtbl: *const winapi::um::oleidl::IDropTargetVtbl,
+    ref_count: AtomicUsize,
+    hwnd: HWND,
+    app_data: SharedApplicationData,
+}
+
+static DROP_TARGET_VTBL: winapi::um::oleidl::IDropTargetVtbl =
+    winapi::um::oleidl::IDropTargetVtbl {
+        parent: winapi::um::unknwnbase::IUnknownVtbl {
+            QueryInterface: drop_target_query_interface,
+            AddRef: drop_target_add_ref,
+            Release: drop_target_release,
+        },
+        DragEnter: drop_target_drag_enter,
+        DragOver: drop_target_drag_over,
+        DragLeave: drop_target_drag_leave,
+        Drop: drop_target_drop,
+    };
+
+impl DropTarget {
+    /// Allocate a new drop target for `hwnd`, returning an owning pointer with a
+    /// reference count of one.
+    fn new(hwnd: HWND, app_data: SharedApplicationData) -> *mut DropTarget {
+        Box::into_raw(Box::new(DropTarget {
+            lpVtbl: &DROP_TARGET_VTBL,
+            ref_count: AtomicUsize::new(1),
+            hwnd,
+            app_data,
+        }))
+    }
+}
+
+unsafe extern "system" fn drop_target_query_interface(
+    this: *mut winapi::um::unknwnbase::IUnknown,
+    riid: winapi::shared::guiddef::REFIID,
+    ppv: *mut *mut c_void,
+) -> HRESULT {
+    use winapi::shared::winerror::{E_NOINTERFACE, E_POINTER, S_OK};
+    use winapi::um::unknwnbase::IUnknown;
+    use winapi::um::oleidl::IDropTarget;
+    use winapi::Interface;
+
+    if ppv.is_null() {
+        return E_POINTER;
+    }
+    // We only expose IUnknown and IDropTarget, which share this object.
+    if guid_eq(&*riid, &IUnknown::uuidof()) || guid_eq(&*riid, &IDropTarget::uuidof()) {
+        drop_target_add_ref(this);
+        *ppv = this as *mut c_void;
+        S_OK
+    } else {
+        *ppv = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+fn guid_eq(a: &winapi::shared::guiddef::GUID, b: &winapi::shared::guiddef::GUID) -> bool {
+    a.Data1 == b.Data1 && a.Data2 == b.Data2 && a.Data3 == b.Data3 && a.Data4 == b.Data4
+}
+
+unsafe extern "system" fn drop_target_add_ref(
+    this: *mut winapi::um::unknwnbase::IUnknown,
+) -> u32 {
+    let this = this as *mut DropTarget;
+    ((*this).ref_count.fetch_add(1, AtomicOrdering::SeqCst) + 1) as u32
+}
+
+unsafe extern "system" fn drop_target_release(
+    this: *mut winapi::um::unknwnbase::IUnknown,
+) -> u32 {
+    let this = this as *mut DropTarget;
+    let prev = (*this).ref_count.fetch_sub(1, AtomicOrdering::SeqCst);
+    if prev == 1 {
+        // Last reference: reclaim the box.
+        drop(Box::from_raw(this));
+        0
+    } else {
+        (prev - 1) as u32
+    }
+}
+
+unsafe extern "system" fn drop_target_drag_enter(
+    this: *mut winapi::um::oleidl::IDropTarget,
+    data_obj: *const winapi::um::objidl::IDataObject,
+    _key_state: u32,
+    pt: winapi::shared::windef::POINTL,
+    effect: *mut u32,
+) -> HRESULT {
+    use winapi::shared::winerror::S_OK;
+    use winapi::um::oleidl::DROPEFFECT_COPY;
+    update_drag_state(this as *mut DropTarget, data_obj, pt, false);
+    if !effect.is_null() {
+        *effect = DROPEFFECT_COPY;
+    }
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drag_over(
+    this: *mut winapi::um::oleidl::IDropTarget,
+    _key_state: u32,
+    pt: winapi::shared::windef::POINTL,
+    effect: *mut u32,
+) -> HRESULT {
+    use winapi::shared::winerror::S_OK;
+    use winapi::um::oleidl::DROPEFFECT_COPY;
+    // `DragOver` does not hand us the `IDataObject` again; the paths captured on
+    // `DragEnter` are still in `hovered_file`, so only the cursor position is
+    // refreshed here (passing a null data object keeps the existing hover list).
+    update_drag_state(this as *mut DropTarget, ptr::null(), pt, false);
+    if !effect.is_null() {
+        *effect = DROPEFFECT_COPY;
+    }
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drag_leave(
+    this: *mut winapi::um::oleidl::IDropTarget,
+) -> HRESULT {
+    use winapi::shared::winerror::S_OK;
+    let this = this as *mut DropTarget;
+    // The drag left the window without dropping: clear the hovered files so
+    // hover styling is removed, then re-run the event cycle.
+    if let Ok(mut appdata) = (*this).app_data.inner.try_borrow_mut() {
+        let hwnd_key = (*this).hwnd as usize;
+        if let Some(window) = appdata.windows.get_mut(&hwnd_key) {
+            let previous_state = window.internal.current_window_state.clone();
+            window.internal.previous_window_state = Some(previous_state);
+            window.internal.current_window_state.hovered_file = None.into();
+            winapi::um::winuser::PostMessageW((*this).hwnd, AZ_REDO_HIT_TEST, 0, 0);
+        }
+    }
+    S_OK
+}
+
+unsafe extern "system" fn drop_target_drop(
+    this: *mut winapi::um::oleidl::IDropTarget,
+    data_obj: *const winapi::um::objidl::IDataObject,
+    _key_state: u32,
+    pt: winapi::shared::windef::POINTL,
+    effect: *mut u32,
+) -> HRESULT {
+    use winapi::shared::winerror::S_OK;
+    use winapi::um::oleidl::DROPEFFECT_COPY;
+    update_drag_state(this as *mut DropTarget, data_obj, pt, true);
+    if !effect.is_null() {
+        *effect = DROPEFFECT_COPY;
+    }
+    S_OK
+}
+
+/// Shared body of the `IDropTarget` callbacks: translate `pt` into the window's
+/// logical client coordinates, stash the dragged file paths into either
+/// `hovered_file` (while dragging) or `dropped_file` (on drop), and re-run the
+/// hit test so node callbacks and `:hover` styling pick the change up.
+///
+/// When `data_obj` is null the current `hovered_file` list is left untouched —
+/// `DragOver` fires repeatedly but only carries a fresh cursor position.
+unsafe fn update_drag_state(
+    this: *mut DropTarget,
+    data_obj: *const winapi::um::objidl::IDataObject,
+    pt: winapi::shared::windef::POINTL,
+    is_drop: bool,
+) {
+    use winapi::um::winuser::ScreenToClient;
+    use winapi::shared::windef::POINT;
+
+    let files = if data_obj.is_null() {
+        None
+    } else {
+        Some(extract_dropped_files(data_obj))
+    };
+
+    // `pt` is in screen coordinates; the hit test wants window-local logical
+    // pixels, so translate and divide by the DPI factor.
+    let mut client = POINT { x: pt.x, y: pt.y };
+    ScreenToClient((*this).hwnd, &mut client);
+
+    if let Ok(mut appdata) = (*this).app_data.inner.try_borrow_mut() {
+        let hwnd_key = (*this).hwnd as usize;
+        if let Some(window) = appdata.windows.get_mut(&hwnd_key) {
+            use azul_core::window::{CursorPosition, LogicalPosition};
+            let hidpi = window.internal.current_window_state.size.hidpi_factor;
+            let previous_state = window.internal.current_window_state.clone();
+            window.internal.previous_window_state = Some(previous_state);
+            window.internal.current_window_state.mouse_state.cursor_position =
+                CursorPosition::InWindow(LogicalPosition::new(
+                    client.x as f32 / hidpi,
+                    client.y as f32 / hidpi,
+                ));
+            if is_drop {
+                // The drop commits the files: move them into `dropped_file` and
+                // clear the transient hover state.
+                set_dropped_files(&mut window.internal.current_window_state, files.unwrap_or_default());
+                window.internal.current_window_state.hovered_file = None.into();
+            } else if let Some(files) = files {
+                set_hovered_files(&mut window.internal.current_window_state, files);
+            }
+            // Re-run the hit test at the cursor point so the target node's
+            // callbacks and hover styling see the dragged / dropped files.
+            winapi::um::winuser::PostMessageW((*this).hwnd, AZ_REDO_HIT_TEST, 0, 0);
+        }
+    }
+}
+
+/// Pull the list of dropped file paths out of a CF_HDROP `IDataObject`.
+unsafe fn extract_dropped_files(
+    data_obj: *const winapi::um::objidl::IDataObject,
+) -> Vec<String> {
+    use winapi::shared::winerror::SUCCEEDED;
+    use winapi::shared::wtypes::CLIPFORMAT;
+    use winapi::um::objidl::{FORMATETC, STGMEDIUM, TYMED_HGLOBAL};
+    use winapi::um::shellapi::{DragQueryFileW, HDROP};
+    use winapi::um::winuser::CF_HDROP;
+
+    // DVASPECT_CONTENT; the full content of the object.
+    const DVASPECT_CONTENT: u32 = 1;
+
+    let mut files = Vec::new();
+    if data_obj.is_null() {
+        return files;
+    }
+
+    let mut format = FORMATETC {
+        cfFormat: CF_HDROP as CLIPFORMAT,
+        ptd: ptr::null_mut(),
+        dwAspect: DVASPECT_CONTENT,
+        lindex: -1,
+        tymed: TYMED_HGLOBAL,
+    };
+    let mut medium: STGMEDIUM = mem::zeroed();
+
+    let get_data = (*(*data_obj).lpVtbl).GetData;
+    let hr = get_data(data_obj as *mut _, &mut format, &mut medium);
+    if !SUCCEEDED(hr) {
+        return files;
+    }
+
+    let hdrop = *medium.u.hGlobal() as HDROP;
+    if !hdrop.is_null() {
+        let count = DragQueryFileW(hdrop, 0xFFFF_FFFF, ptr::null_mut(), 0);
+        for i in 0..count {
+            let needed = DragQueryFileW(hdrop, i, ptr::null_mut(), 0);
+            if needed == 0 {
+                continue;
+            }
+            let mut buffer = vec![0u16; needed as usize + 1];
+            let copied = DragQueryFileW(hdrop, i, buffer.as_mut_ptr(), buffer.len() as u32);
+            if copied > 0 {
+                buffer.truncate(copied as usize);
+                files.push(String::from_utf16_lossy(&buffer));
+            }
+        }
+    }
+
+    winapi::um::ole2::ReleaseStgMedium(&mut medium);
+    files
+}
+
+/// Surface the dropped file paths into the window state so node callbacks can
+/// react to them. Azul's window state models a single dropped file at a time;
+/// the first path is stored and the rest are ignored.
+fn set_dropped_files(window_state: &mut FullWindowState, files: Vec<String>) {
+    window_state.dropped_file = files
+        .into_iter()
+        .next()
+        .map(AzString::from)
+        .into();
+}
+
+/// Surface the currently hovered file paths into the window state while a drag
+/// is in progress. As with [`set_dropped_files`], only the first path is kept
+/// because the window state models a single file at a time.
+fn set_hovered_files(window_state: &mut FullWindowState, files: Vec<String>) {
+    window_state.hovered_file = files
+        .into_iter()
+        .next()
+        .map(AzString::from)
+        .into();
+}
+
+struct Window {
+    /// HWND handle of the plaform window
+    hwnd: HWND,
+    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
+    internal: WindowInternal,
+    /// OpenGL context handle - None if running in software mode
+    gl_context: Option<GlContext>,
+    /// OpenGL functions for faster rendering
+    gl_functions: GlFunctions,
+    /// OpenGL context pointer with compiled SVG and FXAA shaders
+    gl_context_ptr: OptionGlContextPtr,
+    /// Main render API that can be used to register and un-register fonts and images
+    render_api: WrRenderApi,
+    /// WebRender renderer implementation (software or hardware)
+    renderer: Option<WrRenderer>,
+    /// Hit-tester, lazily initialized and updated every time the display list changes layout
+    hit_tester: AsyncHitTester,
+    /// ID -> Callback map for the window menu (default: empty map)
+    menu_callbacks: BTreeMap<u16, MenuCallback>,
+    /// ID -> Callback map for the most recently shown right-click context menu.
+    /// Rebuilt on every `WM_RBUTTONUP` over a node that carries a context menu
+    /// and consumed by `WM_COMMAND`; empty when no popup is active.
+    context_menu_callbacks: BTreeMap<u16, MenuCallback>,
+    /// Keyboard accelerator table for the window menu, translated before normal
+    /// message dispatch so menu shortcuts (e.g. Ctrl+S) fire their callbacks.
+    /// `None` if the window has no menu or no item carries an accelerator.
+    accel_table: Option<HACCEL>,
+    /// Timer ID -> Win32 timer map
+    timers: BTreeMap<TimerId, TIMERPTR>,
+    /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
+    thread_timer_running: Option<TIMERPTR>,
+    /// Hash of the current system menu
+    menu_hash: Option<u64>,
+    /// COM `IDropTarget` registered with `RegisterDragDrop`, kept alive for the
+    /// window's lifetime and revoked / released on `Drop`. `None` if OLE drag
+    /// and drop could not be registered.
+    drop_target: Option<*mut DropTarget>,
+    /// Saved window placement + styles from just before the window went
+    /// fullscreen, so the exact pre-fullscreen geometry can be restored when
+    /// fullscreen is cleared. `None` whenever the window is not fullscreen.
+    pre_fullscreen: Option<PreFullscreenState>,
+    /// App-wide keyboard shortcuts registered for this window, keyed by the
+    /// `(fVirt-mask, virtual-key)` pair produced by [`parse_shortcut`]. Matched
+    /// in the `WM_KEYDOWN` / `WM_SYSKEYDOWN` arms before normal DOM key
+    /// dispatch, so commands like `Ctrl+Shift+P` fire without each layout
+    /// re-implementing modifier tracking.
+    accelerators: BTreeMap<(u8, u16), MenuCallback>,
+    /// Whether the cursor is currently confined to this window's client area.
+    /// Mirrors `flags.is_cursor_locked` but is tracked separately because Win32
+    /// silently drops the `ClipCursor` clip whenever the window loses focus, so
+    /// the grab has to be re-applied on re-activation rather than being a
+    /// fire-and-forget call.
+    cursor_grab: bool,
+    /// How the cursor should behave over this window (normal / confined /
+    /// hidden). Confinement re-uses [`Window::apply_cursor_grab`]; hiding keeps
+    /// a per-window flag so that `ShowCursor` is balanced and only affects this
+    /// window, not every Azul window on the thread.
+    cursor_mode: CursorMode,
+    /// Whether this window currently holds an outstanding `ShowCursor(FALSE)`
+    /// that must be balanced with a `ShowCursor(TRUE)` when the cursor leaves
+    /// the client area or the window loses focus.
+    cursor_hidden: bool,
+    /// Count of WebRender transactions submitted with `generate_frame` that
+    /// have not yet been built. [`Window::request_frame`] increments it;
+    /// `Notifier::new_frame_ready` (running on WebRender's own thread) decrements
+    /// it. Shared via `Arc` rather than kept window-local because the notifier
+    /// only carries the `HWND` and this counter across the thread boundary -
+    /// see [`Window::wait_for_pending_frame`].
+    pending_frames: Arc<AtomicUsize>,
+}
+
+/// Per-window cursor behaviour requested by the layout / callbacks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CursorMode {
+    /// Free cursor, no confinement or hiding (the default).
+    Normal,
+    /// Cursor confined to the window's client area with `ClipCursor`.
+    Confined,
+    /// Cursor hidden while over the window's client area.
+    Hidden,
+}
+
+/// Window placement and styles captured before entering fullscreen so that
+/// clearing fullscreen restores the window to exactly where it was, avoiding
+/// the classic "restore after maximize leaves the wrong size" bug.
+struct PreFullscreenState {
+    placement: winapi::um::winuser::WINDOWPLACEMENT,
+    style: isize,
+    ex_style: isize,
+}
+
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.hwnd.fmt(f)?;
+        self.internal.fmt(f)?;
+        self.gl_context.fmt(f)?;
+        self.gl_context_ptr.fmt(f)?;
+        self.renderer.is_some().fmt(f)?;
+        self.menu_callbacks.fmt(f)?;
+        self.context_menu_callbacks.fmt(f)?;
+        self.accel_table.map(|h| h as usize).fmt(f)?;
+        self.menu_hash.fmt(f)?;
+        Ok(())
+    }
+}
+
+impl Drop for Window {
+    fn drop(&mut self) {
+        if let Some(drop_target) = self.drop_target.take() {
+            use winapi::um::ole2::RevokeDragDrop;
+            use winapi::um::unknwnbase::IUnknown;
+            unsafe {
+                RevokeDragDrop(self.hwnd);
+                // Balance the reference we held since `RegisterDragDrop`.
+                ((*(*drop_target).lpVtbl).parent.Release)(drop_target as *mut IUnknown);
+            }
+        }
+        if let Some(context) = self.gl_context.as_ref() {
+            context.release_current();
+            context.delete();
+        }
+        if let Some(renderer) = self.renderer.take() {
+            renderer.deinit();
+        }
+        if let Some(accel) = self.accel_table.take() {
+            use winapi::um::winuser::DestroyAcceleratorTable;
+            unsafe { DestroyAcceleratorTable(accel) };
+        }
+    }
+}
+
+impl Window {
+
+    fn get_id(&self) -> usize {
+        self.hwnd as usize
+    }
+
+    /// Confine (or release) the system cursor to this window's client area.
+    ///
+    /// Win32 computes `ClipCursor` in screen coordinates, so the client
+    /// `RECT` is mapped through `ClientToScreen` first. Passing a null rect to
+    /// `ClipCursor` releases any previous clip, which is what a cleared grab
+    /// needs. Because the OS drops the clip on focus loss, this is re-issued
+    /// from the `WM_ACTIVATE` / `WM_MOUSEMOVE` paths rather than just once.
+    unsafe fn apply_cursor_grab(&self) {
+        use winapi::um::winuser::{ClientToScreen, ClipCursor, GetClientRect};
+        use winapi::shared::windef::POINT;
+
+        if !self.cursor_grab {
+            ClipCursor(ptr::null());
+            return;
+        }
+
+        let mut rect: RECT = mem::zeroed();
+        if GetClientRect(self.hwnd, &mut rect) == 0 {
+            return;
+        }
+        let mut top_left = POINT { x: rect.left, y: rect.top };
+        let mut bottom_right = POINT { x: rect.right, y: rect.bottom };
+        ClientToScreen(self.hwnd, &mut top_left);
+        ClientToScreen(self.hwnd, &mut bottom_right);
+        let screen_rect = RECT {
+            left: top_left.x,
+            top: top_left.y,
+            right: bottom_right.x,
+            bottom: bottom_right.y,
+        };
+        ClipCursor(&screen_rect);
+    }
+
+    /// Apply the window's [`CursorMode`] while the cursor is over the client
+    /// area (called from `WM_SETFOCUS` and `WM_MOUSEMOVE`). Confinement re-arms
+    /// the clip; hiding issues a single balanced `ShowCursor(FALSE)`.
+    unsafe fn enter_cursor_mode(&mut self) {
+        use winapi::um::winuser::ShowCursor;
+
+        self.cursor_grab = self.cursor_mode == CursorMode::Confined;
+        self.apply_cursor_grab();
+
+        match self.cursor_mode {
+            CursorMode::Hidden if !self.cursor_hidden => {
+                ShowCursor(FALSE);
+                self.cursor_hidden = true;
+            },
+            CursorMode::Normal | CursorMode::Confined if self.cursor_hidden => {
+                ShowCursor(TRUE);
+                self.cursor_hidden = false;
+            },
+            _ => {},
+        }
+    }
+
+    /// Release any cursor confinement / hiding held by this window (called from
+    /// `WM_KILLFOCUS` and `WM_MOUSELEAVE`), without forgetting the requested
+    /// [`CursorMode`] so it can be re-armed when focus returns.
+    unsafe fn leave_cursor_mode(&mut self) {
+        use winapi::um::winuser::{ClipCursor, ShowCursor};
+
+        ClipCursor(ptr::null());
+        if self.cursor_hidden {
+            ShowCursor(TRUE);
+            self.cursor_hidden = false;
+        }
+    }
+
+    // Creates a new HWND according to the options
+    fn create(
+        hinstance: HINSTANCE,
+        mut options: WindowCreateOptions,
+        data: SharedApplicationData,
+    ) -> Result<Self, WindowsWindowCreateError> {
+
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{
+                translate_document_id_wr, translate_id_namespace_wr, wr_translate_debug_flags,
+                wr_translate_document_id,
+            },
+        };
+        use azul_core::{
+            callbacks::PipelineId,
+            gl::GlContextPtr,
+            window::{
+                CursorPosition, HwAcceleration,
+                LogicalPosition, ScrollResult,
+                PhysicalSize, RendererType,
+                WindowInternalInit, FullHitTest,
+                WindowFrame,
+            },
+        };
+        use webrender::api::ColorF as WrColorF;
+        use webrender::ProgramCache as WrProgramCache;
+        use winapi::{
             shared::windef::POINT,
             um::{
                 wingdi::{
-                    wglDeleteContext, wglMakeCurrent,
-                    SwapBuffers, GetDeviceCaps,
+                    GetDeviceCaps,
                     LOGPIXELSX, LOGPIXELSY
                 },
                 winuser::{
@@ -1618,6 +3715,15 @@ impl Window {
             ));
         }
 
+        // Match the non-client area (title bar / borders) to the app's theme.
+        // Without this the caption stays light on a dark theme, which looks
+        // out of place. Re-applied on theme change via WM_SETTINGCHANGE.
+        if let Ok(appdata) = data.inner.try_borrow() {
+            if let Some(dwm) = appdata.dwm.as_ref() {
+                dwm.set_dark_mode(hwnd, theme_is_dark(options.state.theme));
+            }
+        }
+
         // Get / store DPI
         // NOTE: GetDpiForWindow would be easier, but it's Win10 only
         let dpi = unsafe {
@@ -1641,18 +3747,42 @@ impl Window {
             None => vec![RendererType::Hardware, RendererType::Software],
         };
 
-        let mut opengl_context: Option<HGLRC> = None;
+        let mut opengl_context: Option<GlContext> = None;
         let mut rt = RendererType::Software;
         let mut extra = ExtraWglFunctions::default();
         let mut gl = GlFunctions::initialize();
         let mut gl_context_ptr: OptionGlContextPtr = None.into();
 
+        // Version/profile/debug default to a core-profile 3.1 context; only the
+        // swap interval is taken from the window's renderer options so far.
+        let gl_options = GlContextOptions {
+            vsync: options.renderer.as_ref().map(|r| r.vsync),
+            ..GlContextOptions::default()
+        };
+
         for r in renderer_types {
             rt = r;
             match r {
                 RendererType::Software => {}
                 RendererType::Hardware => {
-                    let gl_context_result = create_gl_context(hwnd);
+                    // MSAA sample count; 0/1 disables multisampling. Every
+                    // window uses `DEFAULT_MSAA_SAMPLES` (0), so multisampling
+                    // is off; the count is not read from the window's
+                    // create options.
+                    let samples = DEFAULT_MSAA_SAMPLES;
+                    // Share the GL object namespace with the root window so
+                    // textures / buffers / programs (including the compositor's
+                    // external images) are visible across every window and are
+                    // only uploaded once. The first window to come up becomes
+                    // the share-root; its handle is passed as `hShareContext`.
+                    // EGL contexts share through their common ANGLE display, so
+                    // the handle is ignored when WGL is unavailable.
+                    let share_root = data
+                        .inner
+                        .try_borrow()
+                        .ok()
+                        .and_then(|appdata| appdata.shared_context.and_then(|c| c.wgl_handle()));
+                    let gl_context_result = create_gl_context(hwnd, samples, gl_options, share_root);
                     match gl_context_result {
                         Ok((o, extra_funcs)) => {
                             opengl_context = Some(o);
@@ -1666,30 +3796,20 @@ impl Window {
         }
 
         gl_context_ptr = opengl_context
-            .map(|hrc| unsafe {
+            .map(|context| unsafe {
                 let hdc = GetDC(hwnd);
-                unsafe { wglMakeCurrent(hdc, hrc) };
+                context.make_current(hdc);
                 gl.load();
                 // compiles SVG and FXAA shader programs...
                 let ptr = GlContextPtr::new(rt, gl.functions.clone());
 
-                /*
-                match options.renderer.as_ref().map(|v| v.vsync) {
-                    Some(VSync::Enabled) => {
-                        if let Some(wglSwapIntervalEXT) = extra_functions.wglSwapIntervalEXT {
-                            unsafe { (wglSwapIntervalEXT)(1) };
-                        }
-                    },
-                    Some(VSync::Disabled) => {
-                        if let Some(wglSwapIntervalEXT) = extra_functions.wglSwapIntervalEXT {
-                            unsafe { (wglSwapIntervalEXT)(0) };
-                        }
-                    },
-                    _ => { },
-                }
-                */
+                // Honor the requested vsync mode through WGL_EXT_swap_control.
+                // The call must happen with this window's context current,
+                // which it is here. A missing extension is not an error; we
+                // simply leave the driver default in place.
+                apply_vsync(gl_options.vsync, &extra);
 
-                unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+                context.release_current();
                 ReleaseDC(hwnd, hdc);
                 ptr
             })
@@ -1699,16 +3819,65 @@ impl Window {
         // WindowInternal::new() may dispatch OpenGL calls,
         // need to make context current before invoking
         let hdc = unsafe { GetDC(hwnd) };
-        if let Some(hrc) = opengl_context.as_mut() {
-            unsafe { wglMakeCurrent(hdc, *hrc) };
+        if let Some(context) = opengl_context.as_ref() {
+            context.make_current(hdc);
         }
 
         // Invoke callback to initialize UI for the first time
         let mut initial_resource_updates = Vec::new();
 
+        // Reuse the application-wide compiled-shader cache if a previous window
+        // already built it for the same GL flavor, so WebRender doesn't
+        // recompile the shader set here. A cache built for WGL is unusable
+        // under EGL/ANGLE (and vice versa), so a flavor mismatch falls back to
+        // `None` and lets `WrRenderer::new` compile a fresh set below.
+        let this_api_kind = opengl_context.as_ref().map(|c| c.api_kind());
+        let (shared_shaders, is_first_window) = match data.inner.try_borrow() {
+            Ok(appdata) => {
+                let shaders = appdata
+                    .shader_cache
+                    .as_ref()
+                    .filter(|(kind, _)| Some(*kind) == this_api_kind)
+                    .map(|(_, shaders)| shaders.clone());
+                (shaders, appdata.shader_cache.is_none())
+            }
+            Err(_) => (None, false),
+        };
+
+        let pending_frames = Arc::new(AtomicUsize::new(0));
+
+        // Warm the GL pipeline up front on the very first window of the
+        // process (subsequent windows reuse the shared shader cache from
+        // chunk10-1 and gain nothing from a full recompile here). The on-disk
+        // program cache persists compiled program binaries across runs so
+        // cold-start jank only recurs when the driver invalidates them, not
+        // on every launch; an unwritable cache directory just means the
+        // in-memory cache WebRender already falls back to.
+        let precache_flags = if is_first_window {
+            WrShaderPrecacheFlags::FULL_COMPILE
+        } else {
+            WrShaderPrecacheFlags::EMPTY
+        };
+        let program_cache = WrProgramCache::new(shader_cache_dir());
+
+        // Scene swaps are reported through the same `Notifier` wakeup path as
+        // finished frames (`AZ_COMPOSITE_NEEDED`), just via the
+        // `SceneSwapObserver` trait instead of `RenderNotifier` directly,
+        // since the scene-builder thread talks to WebRender through a
+        // separate hook interface from the renderer's own notifier.
+        let scene_builder_hooks = Box::new(WindowSceneBuilderHooks {
+            observer: Box::new(Notifier {
+                hwnd: hwnd as usize,
+                pending_frames: pending_frames.clone(),
+            }),
+        });
+
         let (mut renderer, sender) = match WrRenderer::new(
             gl.functions.clone(),
-            Box::new(Notifier {}),
+            Box::new(Notifier {
+                hwnd: hwnd as usize,
+                pending_frames: pending_frames.clone(),
+            }),
             WrRendererOptions {
                 resource_override_path: None,
                 use_optimized_shaders: true,
@@ -1722,19 +3891,20 @@ impl Window {
                     a: 0.0,
                 }, // transparent
                 panic_on_gl_error: false,
-                precache_flags: WrShaderPrecacheFlags::EMPTY,
-                cached_programs: Some(WrProgramCache::new(None)),
+                precache_flags,
+                cached_programs: Some(program_cache),
                 enable_multithreading: true,
                 debug_flags: wr_translate_debug_flags(&options.state.debug_state),
+                scene_builder_hooks: Some(scene_builder_hooks),
                 ..WrRendererOptions::default()
             },
-            WR_SHADER_CACHE,
+            shared_shaders.as_ref(),
         ) {
             Ok(o) => o,
             Err(e) => unsafe {
-                if let Some(hrc) = opengl_context.as_mut() {
-                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                    wglDeleteContext(*hrc);
+                if let Some(context) = opengl_context.as_ref() {
+                    context.release_current();
+                    context.delete();
                 }
                 ReleaseDC(hwnd, hdc);
                 DestroyWindow(hwnd);
@@ -1780,9 +3950,9 @@ impl Window {
         let mut appdata_lock = match data.inner.try_borrow_mut() {
             Ok(o) => o,
             Err(e) => unsafe {
-                if let Some(hrc) = opengl_context.as_mut() {
-                    wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
-                    wglDeleteContext(*hrc);
+                if let Some(context) = opengl_context.as_ref() {
+                    context.release_current();
+                    context.delete();
                 }
                 ReleaseDC(hwnd, hdc);
                 DestroyWindow(hwnd);
@@ -1790,6 +3960,27 @@ impl Window {
             },
         };
 
+        // The first window to come up becomes the share-root for GL resources
+        // and seeds the application-wide shader cache; subsequent windows reuse
+        // both (its handle is threaded into `create_gl_context` as the
+        // `hShareContext`; see the `share_root` / `shared_shaders` paths above).
+        // The root is stored here and must outlive its sharers.
+        if appdata_lock.shared_context.is_none() {
+            appdata_lock.shared_context = opengl_context;
+        }
+        // Seed (or rebuild, on a flavor change) the cache with the shaders this
+        // renderer ended up with - either the reused `Rc` above, or the fresh
+        // set it just compiled because there was no cache yet or the cached one
+        // didn't match this window's GL flavor.
+        if let Some(kind) = this_api_kind {
+            let is_stale = appdata_lock
+                .shader_cache
+                .as_ref()
+                .map_or(true, |(cached_kind, _)| *cached_kind != kind);
+            if is_stale {
+                appdata_lock.shader_cache = Some((kind, renderer.shaders.clone()));
+            }
+        }
 
         let mut internal = {
 
@@ -1827,8 +4018,8 @@ impl Window {
         };
 
 
-        if let Some(hrc) = opengl_context.as_ref() {
-            unsafe { wglMakeCurrent(ptr::null_mut(), ptr::null_mut()) };
+        if let Some(context) = opengl_context.as_ref() {
+            context.release_current();
         }
 
         unsafe { ReleaseDC(hwnd, hdc); }
@@ -1837,16 +4028,19 @@ impl Window {
         // before querying the window size again
         let mut menu_callbacks = BTreeMap::new();
         let mut menu_hash = None;
+        let mut accel_table = None;
         if let Some(menu_bar) = internal.get_menu_bar() {
             let WindowsMenuBar {
                 _native_ptr,
                 callbacks,
+                accel,
                 hash,
             } = WindowsMenuBar::new(menu_bar);
             unsafe {
                 SetMenu(hwnd, _native_ptr);
             }
             menu_callbacks = callbacks;
+            accel_table = accel;
             menu_hash = Some(hash);
         }
 
@@ -1933,6 +4127,7 @@ impl Window {
 
         render_api.flush_scene_builder();
 
+        pending_frames.fetch_add(1, AtomicOrdering::SeqCst);
         generate_frame(
             &mut internal,
             &mut render_api,
@@ -1973,6 +4168,27 @@ impl Window {
         unsafe { PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0 ); }
         unsafe { ShowWindow(hwnd, sw_options); }
 
+        // Register the window as an OLE drop target so it can accept dropped
+        // files. `RegisterDragDrop` takes its own reference, so the one minted
+        // by `DropTarget::new` is the one `Window::drop` releases after
+        // `RevokeDragDrop`.
+        let drop_target = {
+            use winapi::um::ole2::RegisterDragDrop;
+            use winapi::shared::winerror::SUCCEEDED;
+            let dt = DropTarget::new(hwnd, data.clone());
+            let hr = unsafe { RegisterDragDrop(hwnd, dt as *mut _) };
+            if SUCCEEDED(hr) {
+                Some(dt)
+            } else {
+                // Registration failed (e.g. OLE not initialized): drop our ref.
+                use winapi::um::unknwnbase::IUnknown;
+                unsafe {
+                    ((*(*dt).lpVtbl).parent.Release)(dt as *mut IUnknown);
+                }
+                None
+            }
+        };
+
         // NOTE: The window is NOT stored yet
         Ok(Window {
             hwnd,
@@ -1984,12 +4200,34 @@ impl Window {
             renderer: Some(renderer),
             hit_tester: AsyncHitTester::Requested(hit_tester),
             menu_callbacks,
+            context_menu_callbacks: BTreeMap::new(),
+            accel_table,
             menu_hash,
             timers: BTreeMap::new(),
             thread_timer_running: None,
+            drop_target,
+            accelerators: BTreeMap::new(),
+            cursor_grab: false,
+            cursor_mode: CursorMode::Normal,
+            cursor_hidden: false,
+            pre_fullscreen: None,
+            pending_frames,
         })
     }
 
+    /// Register an app-wide keyboard shortcut (e.g. `"CmdOrCtrl+S"`) that fires
+    /// `callback` when the combination is pressed while the window has focus.
+    /// Returns an error if the shortcut string is malformed.
+    fn register_accelerator(
+        &mut self,
+        shortcut: &str,
+        callback: MenuCallback,
+    ) -> Result<(), AcceleratorParseError> {
+        let accel = parse_shortcut(shortcut)?;
+        self.accelerators.insert(accel, callback);
+        Ok(())
+    }
+
     fn start_stop_timers(
         &mut self,
         added: FastHashMap<TimerId, Timer>,
@@ -2038,58 +4276,419 @@ impl Window {
     // ScrollResult contains information about what nodes need to be scrolled,
     // whether they were scrolled by the system or by the user and how far they
     // need to be scrolled
-    fn do_system_scroll(&mut self, scroll: ScrollResult) {
-        println!("scroll: {:#?}", scroll); // TODO
-        // for scrolled_node in scroll {
-        //      self.render_api.scroll_node_with_id();
-        //      let scrolled_rect = LogicalRect { origin: scroll_offset, size: visible.size };
-        //      if !scrolled_node.scroll_bounds.contains(&scroll_rect) {
-        //
-        //      }
-        // }
+    fn do_system_scroll(&mut self, _scroll: ScrollResult) {
+        use crate::wr_translate::wr_translate_document_id;
+
+        // Push the accumulated scroll offset of every scrolled node into
+        // WebRender. `scroll_all_nodes` translates each node's external scroll
+        // id and issues `scroll_node_with_id` with `ScrollClamping::ToContentBounds`,
+        // which clamps the requested offset into `[0, content - visible]` so we
+        // never scroll past the content. The offsets themselves live in
+        // `scroll_states`, which `process_system_scroll` has already updated;
+        // `_scroll` only tells us that a scroll happened this frame.
+        let mut txn = WrTransaction::new();
+        scroll_all_nodes(&self.internal.scroll_states, &mut txn);
+        self.render_api.send_transaction(
+            wr_translate_document_id(self.internal.document_id),
+            txn,
+        );
+
+        // Re-composite so the shifted content is drawn.
+        self.request_frame(false);
     }
-}
 
-// function can fail: creates an OpenGL context on the HWND, stores the context on the window-associated data
-fn create_gl_context(hwnd: HWND) -> Result<(HGLRC, ExtraWglFunctions), WindowsOpenGlError> {
-    use winapi::um::{
-        wingdi::{
-            wglCreateContext, wglDeleteContext, wglMakeCurrent, ChoosePixelFormat,
-            DescribePixelFormat, SetPixelFormat, PFD_DOUBLEBUFFER, PFD_DRAW_TO_WINDOW,
-            PFD_MAIN_PLANE, PFD_SUPPORT_OPENGL, PFD_TYPE_RGBA, PIXELFORMATDESCRIPTOR,
-        },
-        winuser::{GetDC, ReleaseDC},
-    };
+    /// Submits a frame-generating transaction and marks it in flight on
+    /// `pending_frames` until `Notifier::new_frame_ready` reports it built.
+    /// Every frame of an existing window goes through here; `Window::create`
+    /// increments `pending_frames` itself before its first `generate_frame`.
+    /// So [`Window::wait_for_pending_frame`] sees every outstanding frame.
+    fn request_frame(&mut self, scrolled: bool) {
+        self.pending_frames.fetch_add(1, AtomicOrdering::SeqCst);
+        generate_frame(&mut self.internal, &mut self.render_api, scrolled);
+    }
 
-    use self::WindowsOpenGlError::*;
+    /// Blocks the calling thread until every frame submitted through
+    /// [`Window::request_frame`] has been built by WebRender (`pending_frames`
+    /// reaches zero), or `timeout` elapses. Returns `true` if it caught up,
+    /// `false` on timeout. Intended for headless capture/readback and layout
+    /// tests, so the framebuffer read back always reflects the most recent
+    /// `set_display_list` transaction rather than a stale frame.
+    fn wait_for_pending_frame(&self, timeout: std::time::Duration) -> bool {
+        let start = std::time::Instant::now();
+        while self.pending_frames.load(AtomicOrdering::SeqCst) > 0 {
+            if start.elapsed() >= timeout {
+                return false;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        true
+    }
 
-    // -- window created, now create OpenGL context
+    /// Recovers from a lost GL context (`make_current` / `swap_buffers`
+    /// returning `false` in the `WM_PAINT` handler) without panicking or
+    /// leaving the window permanently unable to paint. This happens on a GPU
+    /// reset, a driver update, or a dGPU/iGPU switch - the old `HGLRC`/`EglContext`
+    /// is dead, but the window, its `WindowInternal` and its `render_api`
+    /// channel to the scene-builder thread are all still alive and do not need
+    /// to be recreated.
+    ///
+    /// Tears down the old context and `WrRenderer`, creates a fresh context
+    /// (reusing `data`'s share-root / shader cache / on-disk program cache the
+    /// same way [`Window::create`] does if the GL flavor still matches), and
+    /// re-submits the current display list so the window repaints on the next
+    /// `WM_PAINT` instead of staying blank. Returns `false` if the context
+    /// could not be recreated either, in which case the caller falls back to
+    /// the software path.
+    fn recover_lost_gl_context(&mut self, data: &SharedApplicationData) -> bool {
+        use crate::compositor::Compositor;
+        use azul_core::{gl::GlContextPtr, window::RendererType};
+        use webrender::api::ColorF as WrColorF;
+        use webrender::ProgramCache as WrProgramCache;
 
-    let opengl32_dll = load_dll("opengl32.dll").ok_or(OpenGL32DllNotFound(get_last_error()))?;
+        if let Some(context) = self.gl_context.take() {
+            context.release_current();
+            // Only delete it if it isn't the process-wide share-root still
+            // referenced from `ApplicationData.shared_context` - that handle
+            // is torn down by `ApplicationData`'s own drop order instead.
+            let is_share_root = data
+                .inner
+                .try_borrow()
+                .ok()
+                .and_then(|appdata| appdata.shared_context.and_then(|c| c.wgl_handle()))
+                == context.wgl_handle()
+                && context.wgl_handle().is_some();
+            if !is_share_root {
+                context.delete();
+            }
+        }
+        self.renderer = None;
+
+        let gl_options = GlContextOptions::default();
+        let share_root = data
+            .inner
+            .try_borrow()
+            .ok()
+            .and_then(|appdata| appdata.shared_context.and_then(|c| c.wgl_handle()));
+        let (new_context, extra) =
+            match create_gl_context(self.hwnd, DEFAULT_MSAA_SAMPLES, gl_options, share_root) {
+                Ok(o) => o,
+                Err(_) => return false,
+            };
 
-    // Get DC
-    let hDC = unsafe { GetDC(hwnd) };
-    if hDC.is_null() {
-        // unsafe { DestroyWindow(hwnd) };
-        return Err(FailedToGetDC(get_last_error()));
-    }
+        let hdc = unsafe { winapi::um::winuser::GetDC(self.hwnd) };
+        if !new_context.make_current(hdc) {
+            new_context.delete();
+            unsafe { winapi::um::winuser::ReleaseDC(self.hwnd, hdc) };
+            return false;
+        }
+        self.gl_functions.load();
+        apply_vsync(gl_options.vsync, &extra);
+        // Recovery only ever runs on the hardware path - a `None` `gl_context`
+        // takes the software blit path in `WM_PAINT` and never calls here.
+        self.gl_context_ptr =
+            Some(GlContextPtr::new(RendererType::Hardware, self.gl_functions.functions.clone()))
+                .into();
+
+        let new_api_kind = new_context.api_kind();
+        let shared_shaders = data
+            .inner
+            .try_borrow()
+            .ok()
+            .and_then(|appdata| {
+                appdata
+                    .shader_cache
+                    .as_ref()
+                    .filter(|(kind, _)| *kind == new_api_kind)
+                    .map(|(_, shaders)| shaders.clone())
+            });
 
-    // now this is a kludge; we need to pass something in the PIXELFORMATDESCRIPTOR
-    // to SetPixelFormat; it will be ignored, mostly. OTOH we want to send something
-    // sane, we're nice people after all - it doesn't hurt if this fails.
-    let mut pfd = PIXELFORMATDESCRIPTOR {
-        nSize: mem::size_of::<PIXELFORMATDESCRIPTOR> as u16,
-        nVersion: 1,
-        dwFlags: {
-            PFD_DRAW_TO_WINDOW |   // support window
-            PFD_SUPPORT_OPENGL |   // support OpenGL
-            PFD_DOUBLEBUFFER // double buffered
-        },
-        iPixelType: PFD_TYPE_RGBA as u8,
-        cColorBits: 24,
-        cRedBits: 0,
-        cRedShift: 0,
-        cGreenBits: 0,
+        let pending_frames = self.pending_frames.clone();
+        let scene_builder_hooks = Box::new(WindowSceneBuilderHooks {
+            observer: Box::new(Notifier {
+                hwnd: self.hwnd as usize,
+                pending_frames: pending_frames.clone(),
+            }),
+        });
+
+        let (renderer, _sender) = match WrRenderer::new(
+            self.gl_functions.functions.clone(),
+            Box::new(Notifier {
+                hwnd: self.hwnd as usize,
+                pending_frames,
+            }),
+            WrRendererOptions {
+                resource_override_path: None,
+                use_optimized_shaders: true,
+                enable_aa: true,
+                enable_subpixel_aa: true,
+                force_subpixel_aa: true,
+                clear_color: WrColorF {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                },
+                panic_on_gl_error: false,
+                precache_flags: WrShaderPrecacheFlags::EMPTY,
+                cached_programs: Some(WrProgramCache::new(shader_cache_dir())),
+                enable_multithreading: true,
+                scene_builder_hooks: Some(scene_builder_hooks),
+                ..WrRendererOptions::default()
+            },
+            shared_shaders.as_ref(),
+        ) {
+            Ok(o) => o,
+            Err(_) => {
+                new_context.release_current();
+                new_context.delete();
+                unsafe { winapi::um::winuser::ReleaseDC(self.hwnd, hdc) };
+                return false;
+            }
+        };
+
+        let mut renderer = renderer;
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        if let Ok(mut appdata_lock) = data.inner.try_borrow_mut() {
+            if appdata_lock.shared_context.is_none() {
+                appdata_lock.shared_context = Some(new_context);
+            }
+            let is_stale = appdata_lock
+                .shader_cache
+                .as_ref()
+                .map_or(true, |(cached_kind, _)| *cached_kind != new_api_kind);
+            if is_stale {
+                appdata_lock.shader_cache = Some((new_api_kind, renderer.shaders.clone()));
+            }
+        }
+
+        new_context.release_current();
+        unsafe { winapi::um::winuser::ReleaseDC(self.hwnd, hdc) };
+
+        self.gl_context = Some(new_context);
+        self.renderer = Some(renderer);
+
+        // Replay the current display list so the freshly created renderer has
+        // something to paint instead of a blank frame.
+        self.request_frame(true);
+
+        true
+    }
+}
+
+/// Logical height of the draggable caption strip along the top edge of
+/// borderless windows.
+const DEFAULT_CAPTION_DRAG_HEIGHT: f32 = 32.0;
+
+/// Whether the cursor (in window-local logical coordinates) is over a region
+/// that should move the window when dragged.
+///
+/// Returns `true` inside the caption strip of `DEFAULT_CAPTION_DRAG_HEIGHT`
+/// along the top edge. The DOM is not consulted: no node can mark itself, or
+/// any other area, as a drag region.
+fn cursor_over_drag_region(window: &Window, pos: azul_core::window::LogicalPosition) -> bool {
+    let _ = window;
+    pos.y >= 0.0 && pos.y < DEFAULT_CAPTION_DRAG_HEIGHT
+}
+
+/// Pixels scrolled per line of mouse-wheel travel. The system reports the
+/// number of lines per notch (`SPI_GETWHEELSCROLLLINES`); this is the physical
+/// size of one of those lines.
+const WHEEL_SCROLL_PIXELS_PER_LINE: f32 = 16.0;
+
+/// Fallback pixels per notch when the system wheel-scroll setting is
+/// unavailable (three lines, the Windows default).
+const WHEEL_SCROLL_PIXELS_PER_NOTCH: f32 = WHEEL_SCROLL_PIXELS_PER_LINE * 3.0;
+
+/// Pixels scrolled per mouse-wheel notch, honoring the user's
+/// `SPI_GETWHEELSCROLLLINES` setting. A value of `WHEEL_PAGESCROLL` (`-1`)
+/// requests a full page; we approximate that with eight lines. Falls back to
+/// the Windows default of three lines when the query fails.
+fn wheel_scroll_pixels_per_notch() -> f32 {
+    use winapi::um::winuser::{SystemParametersInfoW, SPI_GETWHEELSCROLLLINES, WHEEL_PAGESCROLL};
+
+    let mut lines: u32 = 3;
+    let ok = unsafe {
+        SystemParametersInfoW(
+            SPI_GETWHEELSCROLLLINES,
+            0,
+            &mut lines as *mut u32 as *mut c_void,
+            0,
+        )
+    };
+    if ok == FALSE {
+        return WHEEL_SCROLL_PIXELS_PER_NOTCH;
+    }
+    if lines == WHEEL_PAGESCROLL {
+        WHEEL_SCROLL_PIXELS_PER_LINE * 8.0
+    } else {
+        lines as f32 * WHEEL_SCROLL_PIXELS_PER_LINE
+    }
+}
+
+/// Translate a `WM_HSCROLL` / `WM_VSCROLL` request code (the low word of
+/// `wParam`) into a scroll delta in pixels, or `None` for codes that don't map
+/// to a simple line/page step (thumb drags and end-of-scroll notifications).
+fn scrollbar_request_to_delta(wparam: WPARAM) -> Option<f32> {
+    use winapi::shared::minwindef::LOWORD;
+    use winapi::um::winuser::{SB_LINEDOWN, SB_LINEUP, SB_PAGEDOWN, SB_PAGEUP};
+
+    // A page step is several lines, matching a typical scrollbar.
+    const LINE: f32 = WHEEL_SCROLL_PIXELS_PER_NOTCH;
+    const PAGE: f32 = WHEEL_SCROLL_PIXELS_PER_NOTCH * 8.0;
+
+    // SB_LINEUP/SB_LINELEFT and SB_LINEDOWN/SB_LINERIGHT share the same numeric
+    // values, so this covers both the horizontal and vertical message.
+    match LOWORD(wparam as u32) as u32 {
+        x if x == SB_LINEUP as u32 => Some(LINE),
+        x if x == SB_LINEDOWN as u32 => Some(-LINE),
+        x if x == SB_PAGEUP as u32 => Some(PAGE),
+        x if x == SB_PAGEDOWN as u32 => Some(-PAGE),
+        _ => None,
+    }
+}
+
+/// Applies the requested vsync mode via `wglSwapIntervalEXT`: interval `1` for
+/// on, `0` for off, and `-1` for adaptive when `WGL_EXT_swap_control_tear` is
+/// present (falling back to `1`). Must be called with the target context
+/// current. A missing extension is silently ignored.
+fn apply_vsync(vsync: Option<Vsync>, extra: &ExtraWglFunctions) {
+    let Some(wglSwapIntervalEXT) = extra.wglSwapIntervalEXT else {
+        return;
+    };
+
+    let interval = match vsync {
+        Some(Vsync::Enabled) => 1,
+        Some(Vsync::Disabled) => 0,
+        Some(Vsync::Adaptive) => {
+            if extra.swap_control_tear {
+                -1
+            } else {
+                1
+            }
+        }
+        // DontCare / unset: leave the driver default untouched
+        _ => return,
+    };
+
+    unsafe { (wglSwapIntervalEXT)(interval) };
+}
+
+// function can fail: creates an OpenGL context on the HWND, stores the context on the window-associated data
+/// Multisample sample count for hardware GL surfaces. WebRender antialiases
+/// internally, so MSAA is off; no window option changes this value.
+const DEFAULT_MSAA_SAMPLES: u32 = 0;
+
+/// The OpenGL profile a context is requested against.
+#[derive(Debug, Copy, Clone)]
+enum GlProfile {
+    /// `WGL_CONTEXT_CORE_PROFILE_BIT_ARB` - required for the modern VAO/VBO
+    /// path and an sRGB default framebuffer.
+    Core,
+    /// `WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB` - keeps the fixed-function
+    /// pipeline available for legacy apps.
+    Compatibility,
+}
+
+/// Attributes negotiated when creating the GL context, mirroring the knobs
+/// glutin exposes through `GlAttributes`: the requested version, the profile,
+/// whether a debug context is requested and the swap interval. Built from the
+/// window's renderer options and threaded into [`create_gl_context`].
+#[derive(Debug, Copy, Clone)]
+struct GlContextOptions {
+    major: i32,
+    minor: i32,
+    profile: GlProfile,
+    debug: bool,
+    vsync: Option<Vsync>,
+}
+
+impl Default for GlContextOptions {
+    fn default() -> Self {
+        // A core-profile OpenGL 3.1 context is what WebRender needs; vsync is
+        // left to the driver default until an app requests otherwise.
+        GlContextOptions {
+            major: 3,
+            minor: 1,
+            profile: GlProfile::Core,
+            debug: false,
+            vsync: None,
+        }
+    }
+}
+
+/// Directory the WebRender program binary cache is persisted to, so compiled
+/// shader programs survive across launches instead of paying the cold-start
+/// compile cost every time. `%LOCALAPPDATA%\Azul\wr-shader-cache`, created on
+/// demand; `None` if `%LOCALAPPDATA%` isn't set or the directory can't be
+/// created (read-only profile, roaming policy, ...), in which case the caller
+/// falls back to WebRender's in-memory-only cache.
+fn shader_cache_dir() -> Option<std::path::PathBuf> {
+    let dir = std::path::PathBuf::from(std::env::var_os("LOCALAPPDATA")?)
+        .join("Azul")
+        .join("wr-shader-cache");
+    std::fs::create_dir_all(&dir).ok()?;
+    Some(dir)
+}
+
+/// Create an OpenGL context for `hwnd`.
+///
+/// When `share_with` is `Some`, the new context shares its object namespace
+/// (textures, buffers, shaders — including the `Compositor` external images)
+/// with that context by passing it as the `hShareContext` argument to
+/// `wglCreateContextAttribsARB`. The application threads its "root" context
+/// handle in here so every window re-uses the root's uploaded font atlases and
+/// image resources instead of re-uploading its own.
+///
+/// Lifetime invariant: the root context passed as `share_with` must outlive
+/// every context created against it. Destroying the root while a sharer is
+/// still alive leaves the sharer pointing at a freed namespace, so teardown
+/// destroys the shared children before the root (see [`ApplicationData`] drop
+/// order and [`Window`]'s `Drop`).
+fn create_gl_context(
+    hwnd: HWND,
+    samples: u32,
+    options: GlContextOptions,
+    share_with: Option<HGLRC>,
+) -> Result<(GlContext, ExtraWglFunctions), WindowsOpenGlError> {
+    use winapi::um::{
+        wingdi::{
+            wglCreateContext, wglDeleteContext, wglMakeCurrent, ChoosePixelFormat,
+            DescribePixelFormat, SetPixelFormat, PFD_DOUBLEBUFFER, PFD_DRAW_TO_WINDOW,
+            PFD_MAIN_PLANE, PFD_SUPPORT_OPENGL, PFD_TYPE_RGBA, PIXELFORMATDESCRIPTOR,
+        },
+        winuser::{GetDC, ReleaseDC},
+    };
+
+    use self::WindowsOpenGlError::*;
+
+    // -- window created, now create OpenGL context
+
+    let opengl32_dll = load_dll("opengl32.dll").ok_or(OpenGL32DllNotFound(get_last_error()))?;
+
+    // Get DC
+    let hDC = unsafe { GetDC(hwnd) };
+    if hDC.is_null() {
+        // unsafe { DestroyWindow(hwnd) };
+        return Err(FailedToGetDC(get_last_error()));
+    }
+
+    // now this is a kludge; we need to pass something in the PIXELFORMATDESCRIPTOR
+    // to SetPixelFormat; it will be ignored, mostly. OTOH we want to send something
+    // sane, we're nice people after all - it doesn't hurt if this fails.
+    let mut pfd = PIXELFORMATDESCRIPTOR {
+        nSize: mem::size_of::<PIXELFORMATDESCRIPTOR> as u16,
+        nVersion: 1,
+        dwFlags: {
+            PFD_DRAW_TO_WINDOW |   // support window
+            PFD_SUPPORT_OPENGL |   // support OpenGL
+            PFD_DOUBLEBUFFER // double buffered
+        },
+        iPixelType: PFD_TYPE_RGBA as u8,
+        cColorBits: 24,
+        cRedBits: 0,
+        cRedShift: 0,
+        cGreenBits: 0,
         cGreenShift: 0,
         cBlueBits: 0,
         cBlueShift: 0,
@@ -2159,6 +4758,7 @@ fn create_gl_context(hwnd: HWND) -> Result<(HGLRC, ExtraWglFunctions), WindowsOp
         const WGL_ALPHA_BITS_ARB: i32 = 0x201B;
         const WGL_DEPTH_BITS_ARB: i32 = 0x2022;
         const WGL_STENCIL_BITS_ARB: i32 = 0x2023;
+        const WGL_FRAMEBUFFER_SRGB_CAPABLE_ARB: i32 = 0x20A9;
 
         let attribs = [
             WGL_DRAW_TO_WINDOW_ARB,
@@ -2185,6 +4785,8 @@ fn create_gl_context(hwnd: HWND) -> Result<(HGLRC, ExtraWglFunctions), WindowsOp
             24,
             WGL_STENCIL_BITS_ARB,
             8,
+            WGL_FRAMEBUFFER_SRGB_CAPABLE_ARB,
+            TRUE,
             0,
             0,
         ];
@@ -2218,15 +4820,111 @@ fn create_gl_context(hwnd: HWND) -> Result<(HGLRC, ExtraWglFunctions), WindowsOp
         }
     }
 
+    // A multisampled format additionally requests `WGL_SAMPLE_BUFFERS_ARB` /
+    // `WGL_SAMPLES_ARB`, negotiating the highest sample count ≤ the requested
+    // one that a format still satisfying the transparency/color/depth
+    // constraints supports. `samples <= 1` means "no MSAA".
+    fn get_multisample_pixel_format_index(
+        hDC: HDC,
+        extra_functions: &ExtraWglFunctions,
+        samples: u32,
+    ) -> Option<i32> {
+        const WGL_DRAW_TO_WINDOW_ARB: i32 = 0x2001;
+        const WGL_DOUBLE_BUFFER_ARB: i32 = 0x2011;
+        const WGL_SUPPORT_OPENGL_ARB: i32 = 0x2010;
+        const WGL_PIXEL_TYPE_ARB: i32 = 0x2013;
+        const WGL_TYPE_RGBA_ARB: i32 = 0x202B;
+        const WGL_TRANSPARENT_ARB: i32 = 0x200A;
+        const WGL_COLOR_BITS_ARB: i32 = 0x2014;
+        const WGL_ALPHA_BITS_ARB: i32 = 0x201B;
+        const WGL_DEPTH_BITS_ARB: i32 = 0x2022;
+        const WGL_STENCIL_BITS_ARB: i32 = 0x2023;
+        const WGL_FRAMEBUFFER_SRGB_CAPABLE_ARB: i32 = 0x20A9;
+        const WGL_SAMPLE_BUFFERS_ARB: i32 = 0x2041;
+        const WGL_SAMPLES_ARB: i32 = 0x2042;
+
+        let wglarb_ChoosePixelFormatARB = extra_functions.wglChoosePixelFormatARB?;
+
+        // Try the requested count, then halve down to 2x; give up (caller falls
+        // back to the single-sample transparent format) if none match.
+        let mut candidate = samples;
+        while candidate >= 2 {
+            let attribs = [
+                WGL_DRAW_TO_WINDOW_ARB,
+                TRUE,
+                WGL_DOUBLE_BUFFER_ARB,
+                TRUE,
+                WGL_SUPPORT_OPENGL_ARB,
+                TRUE,
+                WGL_PIXEL_TYPE_ARB,
+                WGL_TYPE_RGBA_ARB,
+                WGL_TRANSPARENT_ARB,
+                TRUE,
+                WGL_COLOR_BITS_ARB,
+                32,
+                WGL_ALPHA_BITS_ARB,
+                8,
+                WGL_DEPTH_BITS_ARB,
+                24,
+                WGL_STENCIL_BITS_ARB,
+                8,
+                WGL_FRAMEBUFFER_SRGB_CAPABLE_ARB,
+                TRUE,
+                WGL_SAMPLE_BUFFERS_ARB,
+                1,
+                WGL_SAMPLES_ARB,
+                candidate as i32,
+                0,
+                0,
+            ];
+
+            let mut pixel_format = 0;
+            let mut num_pixel_formats = 0;
+            let result = unsafe {
+                (wglarb_ChoosePixelFormatARB)(
+                    hDC,
+                    &attribs[..],
+                    ptr::null(),
+                    1,
+                    &mut pixel_format,
+                    &mut num_pixel_formats,
+                )
+            };
+
+            if result == TRUE && num_pixel_formats != 0 {
+                return Some(pixel_format);
+            }
+            candidate /= 2;
+        }
+
+        None
+    }
+
     let mut b_transparent_succeeded = false;
-    let transparent_opengl_pixelformat_index =
-        match get_transparent_pixel_format_index(hDC, &extra_functions) {
+    let mut b_multisample_succeeded = false;
+
+    // Prefer a multisampled format when one was requested and negotiated; it
+    // already carries the transparency/sRGB constraints. Otherwise fall back to
+    // the plain transparent format, then to the default single-sample one.
+    let transparent_opengl_pixelformat_index = match get_multisample_pixel_format_index(
+        hDC,
+        &extra_functions,
+        samples,
+    ) {
+        Some(i) => {
+            b_multisample_succeeded = true;
+            b_transparent_succeeded = true;
+            i
+        }
+        None => match get_transparent_pixel_format_index(hDC, &extra_functions) {
             Some(i) => {
                 b_transparent_succeeded = true;
                 i
             }
             None => default_pixel_format,
-        };
+        },
+    };
+    let _ = b_multisample_succeeded;
 
     // destroy the dummy context
     unsafe {
@@ -2251,77 +4949,378 @@ fn create_gl_context(hwnd: HWND) -> Result<(HGLRC, ExtraWglFunctions), WindowsOp
     // https://www.khronos.org/registry/OpenGL/extensions/ARB/WGL_ARB_create_context.txt
     const WGL_CONTEXT_MAJOR_VERSION_ARB: i32 = 0x2091;
     const WGL_CONTEXT_MINOR_VERSION_ARB: i32 = 0x2092;
-
-    // Create OpenGL 3.1 context
+    const WGL_CONTEXT_FLAGS_ARB: i32 = 0x2094;
+    const WGL_CONTEXT_PROFILE_MASK_ARB: i32 = 0x9126;
+    const WGL_CONTEXT_DEBUG_BIT_ARB: i32 = 0x0001;
+    const WGL_CONTEXT_CORE_PROFILE_BIT_ARB: i32 = 0x0001;
+    const WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB: i32 = 0x0002;
+
+    // Request the version/profile the caller asked for. A core profile (the
+    // default) is required for an sRGB default framebuffer and the modern
+    // VAO/VBO path WebRender uses; a debug context enables driver validation
+    // messages through `KHR_debug`.
+    let profile_bit = match options.profile {
+        GlProfile::Core => WGL_CONTEXT_CORE_PROFILE_BIT_ARB,
+        GlProfile::Compatibility => WGL_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB,
+    };
+    let flags = if options.debug {
+        WGL_CONTEXT_DEBUG_BIT_ARB
+    } else {
+        0
+    };
     let context_attribs = [
         WGL_CONTEXT_MAJOR_VERSION_ARB,
-        3,
+        options.major,
         WGL_CONTEXT_MINOR_VERSION_ARB,
-        1,
+        options.minor,
+        WGL_CONTEXT_PROFILE_MASK_ARB,
+        profile_bit,
+        WGL_CONTEXT_FLAGS_ARB,
+        flags,
         0,
         0,
     ];
 
-    let CreateContextAttribsARB = if b_transparent_succeeded {
-        extra_functions.wglCreateContextAttribsARB
-    } else {
-        None
-    };
-
-    let hRC = match CreateContextAttribsARB {
-        Some(func) => unsafe { (func)(hDC, ptr::null_mut(), &context_attribs[..]) },
+    // Use the ARB creation path whenever the entry point resolved, not only for
+    // transparent windows: it is the only way to pin a profile/version. The
+    // legacy `wglCreateContext` remains the fallback when the ARB extension is
+    // unavailable.
+    let share_context = share_with.unwrap_or(ptr::null_mut());
+    let hRC = match extra_functions.wglCreateContextAttribsARB {
+        Some(func) => {
+            let arb = unsafe { (func)(hDC, share_context, &context_attribs[..]) };
+            if arb.is_null() {
+                unsafe { wglCreateContext(hDC) }
+            } else {
+                arb
+            }
+        }
         None => unsafe { wglCreateContext(hDC) },
     };
 
     if hRC.is_null() {
+        // Native desktop GL is unavailable (broken or software-only ICD). Fall
+        // back to an ANGLE EGL context on the same HWND before giving up, so
+        // Azul still runs under VM / RDP sessions where only ANGLE is present.
         unsafe {
             ReleaseDC(hwnd, hDC);
         }
-        return Err(OpenGLNotAvailable(get_last_error()));
+        return match create_egl_context(hwnd) {
+            Ok(egl) => Ok((GlContext::Egl(egl), extra_functions)),
+            Err(_) => Err(OpenGLNotAvailable(get_last_error())),
+        };
     }
 
     // return final context
     unsafe {
         ReleaseDC(hwnd, hDC);
     }
-    return Ok((hRC, extra_functions));
+    return Ok((GlContext::Wgl(hRC), extra_functions));
+}
+
+/// Create an OpenGL ES context through ANGLE's EGL implementation
+/// (`libEGL.dll` / `libGLESv2.dll`) targeting `hwnd`. Used as the fallback when
+/// native WGL context creation fails; see [`create_gl_context`].
+fn create_egl_context(hwnd: HWND) -> Result<EglContext, WindowsOpenGlError> {
+    use winapi::um::libloaderapi::GetProcAddress;
+
+    use self::WindowsOpenGlError::*;
+
+    // https://www.khronos.org/registry/EGL/api/EGL/eglplatform.h
+    const EGL_OPENGL_ES_API: u32 = 0x30A0;
+    const EGL_SURFACE_TYPE: i32 = 0x3033;
+    const EGL_WINDOW_BIT: i32 = 0x0004;
+    const EGL_RENDERABLE_TYPE: i32 = 0x3040;
+    const EGL_OPENGL_ES2_BIT: i32 = 0x0004;
+    const EGL_RED_SIZE: i32 = 0x3024;
+    const EGL_GREEN_SIZE: i32 = 0x3023;
+    const EGL_BLUE_SIZE: i32 = 0x3022;
+    const EGL_ALPHA_SIZE: i32 = 0x3021;
+    const EGL_DEPTH_SIZE: i32 = 0x3025;
+    const EGL_STENCIL_SIZE: i32 = 0x3026;
+    const EGL_NONE: i32 = 0x3038;
+    const EGL_CONTEXT_CLIENT_VERSION: i32 = 0x3098;
+
+    // ANGLE ships GL ES through these two DLLs; both must be present for a
+    // working context. `libGLESv2.dll` is loaded so the GL entry points it
+    // exports are available once the context is current.
+    let egl_dll = load_dll("libEGL.dll").ok_or(EglDllNotFound(get_last_error()))?;
+    let _gles_dll = load_dll("libGLESv2.dll").ok_or(EglDllNotFound(get_last_error()))?;
+
+    // Resolve every entry point up front; a missing one means the ANGLE build
+    // is too old to use, so treat it the same as "no EGL available".
+    macro_rules! load_egl {
+        ($name:expr) => {{
+            let mut n = encode_ascii($name);
+            let proc = unsafe { GetProcAddress(egl_dll, n.as_mut_ptr()) };
+            if proc.is_null() {
+                return Err(EglInitFailed(get_last_error()));
+            }
+            unsafe { mem::transmute(proc) }
+        }};
+    }
+
+    let egl = EglFunctions {
+        eglGetDisplay: load_egl!("eglGetDisplay"),
+        eglInitialize: load_egl!("eglInitialize"),
+        eglBindAPI: load_egl!("eglBindAPI"),
+        eglChooseConfig: load_egl!("eglChooseConfig"),
+        eglCreateContext: load_egl!("eglCreateContext"),
+        eglCreateWindowSurface: load_egl!("eglCreateWindowSurface"),
+        eglMakeCurrent: load_egl!("eglMakeCurrent"),
+        eglSwapBuffers: load_egl!("eglSwapBuffers"),
+        eglDestroyContext: load_egl!("eglDestroyContext"),
+        eglDestroySurface: load_egl!("eglDestroySurface"),
+        eglTerminate: load_egl!("eglTerminate"),
+    };
+
+    // EGL_DEFAULT_DISPLAY == null; ANGLE picks its default (D3D11) backend.
+    let display = (egl.eglGetDisplay)(ptr::null_mut());
+    if display.is_null() {
+        return Err(EglInitFailed(get_last_error()));
+    }
+
+    let mut major = 0;
+    let mut minor = 0;
+    if (egl.eglInitialize)(display, &mut major, &mut minor) != TRUE as u32 {
+        return Err(EglInitFailed(get_last_error()));
+    }
+
+    (egl.eglBindAPI)(EGL_OPENGL_ES_API);
+
+    // Request an 8-bit RGBA config so transparent windows keep their alpha
+    // channel, matching the WGL `WGL_ALPHA_BITS_ARB = 8` path above.
+    let config_attribs = [
+        EGL_SURFACE_TYPE,
+        EGL_WINDOW_BIT,
+        EGL_RENDERABLE_TYPE,
+        EGL_OPENGL_ES2_BIT,
+        EGL_RED_SIZE,
+        8,
+        EGL_GREEN_SIZE,
+        8,
+        EGL_BLUE_SIZE,
+        8,
+        EGL_ALPHA_SIZE,
+        8,
+        EGL_DEPTH_SIZE,
+        24,
+        EGL_STENCIL_SIZE,
+        8,
+        EGL_NONE,
+    ];
+
+    let mut config: *mut gl_context_loader::c_void = ptr::null_mut();
+    let mut num_configs = 0;
+    let chose = (egl.eglChooseConfig)(
+        display,
+        config_attribs.as_ptr(),
+        &mut config,
+        1,
+        &mut num_configs,
+    );
+    if chose != TRUE as u32 || num_configs == 0 {
+        (egl.eglTerminate)(display);
+        return Err(EglNoMatchingConfig(get_last_error()));
+    }
+
+    let surface = (egl.eglCreateWindowSurface)(display, config, hwnd, ptr::null());
+    if surface.is_null() {
+        (egl.eglTerminate)(display);
+        return Err(EglContextCreationFailed(get_last_error()));
+    }
+
+    // Match the GL ES 3.0 client version ANGLE maps onto the desktop GL 3.1
+    // core profile the WGL path requests.
+    let context_attribs = [EGL_CONTEXT_CLIENT_VERSION, 3, EGL_NONE];
+    let context =
+        (egl.eglCreateContext)(display, config, ptr::null_mut(), context_attribs.as_ptr());
+    if context.is_null() {
+        (egl.eglDestroySurface)(display, surface);
+        (egl.eglTerminate)(display);
+        return Err(EglContextCreationFailed(get_last_error()));
+    }
+
+    Ok(EglContext {
+        display,
+        surface,
+        context,
+        egl,
+    })
 }
 
 struct WindowsMenuBar {
     _native_ptr: HMENU,
     /// Map from Command -> callback to call
     callbacks: BTreeMap<u16, MenuCallback>,
+    /// Keyboard accelerator table built from the menu items that carry a
+    /// shortcut, keyed by the same command IDs stored in `callbacks`. `None`
+    /// when no item has an accelerator.
+    accel: Option<HACCEL>,
     hash: u64,
 }
 
 static WINDOWS_UNIQUE_COMMAND_ID_GENERATOR: AtomicUsize = AtomicUsize::new(1); // 0 = no command
 
+/// Why an accelerator string such as `"CmdOrCtrl+Shift+P"` could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AcceleratorParseError {
+    /// The string contained no key component, only modifiers (or was empty).
+    MissingKey,
+    /// More than one non-modifier key was given (e.g. `"Ctrl+A+B"`).
+    MultipleKeys,
+    /// The key component isn't one of the supported set.
+    UnknownKey(String),
+}
+
+/// Parse a human-readable accelerator string such as `"Ctrl+S"`, `"Alt+F4"` or
+/// `"Ctrl+Shift+,"` into a Win32 `ACCEL` `fVirt` mask plus virtual-key code.
+/// Returns `None` if the key component isn't one of the supported set (letters,
+/// digits, `F1`-`F24` and the common punctuation keys).
+fn parse_accelerator(shortcut: &str) -> Option<(u8, u16)> {
+    parse_shortcut(shortcut).ok()
+}
+
+/// Fallible accelerator parser used by the app-wide shortcut table. Accepts the
+/// same modifier tokens as the menu parser plus the cross-platform `CmdOrCtrl`
+/// / `Cmd` / `Super` aliases (all of which fold onto `Ctrl` on Win32), and
+/// returns a typed error on malformed input instead of silently dropping the
+/// binding.
+fn parse_shortcut(shortcut: &str) -> Result<(u8, u16), AcceleratorParseError> {
+    use winapi::um::winuser::{FALT, FCONTROL, FSHIFT, FVIRTKEY};
+
+    let mut fvirt = FVIRTKEY;
+    let mut key: Option<u16> = None;
+
+    for part in shortcut.split('+') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "cmdorctrl" | "cmd" | "command"
+            | "super" | "meta" | "win" => fvirt |= FCONTROL,
+            "alt" | "option" => fvirt |= FALT,
+            "shift" => fvirt |= FSHIFT,
+            other => {
+                if key.is_some() {
+                    return Err(AcceleratorParseError::MultipleKeys);
+                }
+                key = Some(
+                    virtual_key_from_str(other)
+                        .ok_or_else(|| AcceleratorParseError::UnknownKey(part.to_string()))?,
+                );
+            }
+        }
+    }
+
+    key.map(|k| (fvirt, k)).ok_or(AcceleratorParseError::MissingKey)
+}
+
+/// Map the key component of an accelerator to its Win32 virtual-key code.
+fn virtual_key_from_str(key: &str) -> Option<u16> {
+    use winapi::um::winuser::{VK_F1, VK_OEM_1, VK_OEM_2, VK_OEM_3, VK_OEM_4, VK_OEM_5, VK_OEM_6,
+        VK_OEM_7, VK_OEM_COMMA, VK_OEM_MINUS, VK_OEM_PERIOD, VK_OEM_PLUS, VK_SPACE, VK_TAB};
+
+    // Single letters and digits map straight onto their ASCII codepoint, which
+    // is what the Win32 virtual-key space uses for 'A'-'Z' and '0'-'9'.
+    if key.len() == 1 {
+        let c = key.chars().next().unwrap().to_ascii_uppercase();
+        return match c {
+            'A'..='Z' | '0'..='9' => Some(c as u16),
+            ',' => Some(VK_OEM_COMMA as u16),
+            '.' => Some(VK_OEM_PERIOD as u16),
+            '/' => Some(VK_OEM_2 as u16),
+            ';' => Some(VK_OEM_1 as u16),
+            '=' | '+' => Some(VK_OEM_PLUS as u16),
+            '-' => Some(VK_OEM_MINUS as u16),
+            '[' => Some(VK_OEM_4 as u16),
+            ']' => Some(VK_OEM_6 as u16),
+            '\\' => Some(VK_OEM_5 as u16),
+            '\'' => Some(VK_OEM_7 as u16),
+            '`' => Some(VK_OEM_3 as u16),
+            _ => None,
+        };
+    }
+
+    // Named non-character keys.
+    let lower = key.to_ascii_lowercase();
+    match lower.as_str() {
+        "space" => return Some(VK_SPACE as u16),
+        "tab" => return Some(VK_TAB as u16),
+        _ => {}
+    }
+
+    // Function keys F1-F24 are consecutive starting at VK_F1.
+    if let Some(num) = lower.strip_prefix('f').and_then(|n| n.parse::<u16>().ok()) {
+        if (1..=24).contains(&num) {
+            return Some(VK_F1 as u16 + (num - 1));
+        }
+    }
+
+    None
+}
+
 impl WindowsMenuBar {
 
     fn new(new: &Menu) -> Self {
-        use winapi::um::winuser::CreateMenu;
+        use winapi::um::winuser::{CreateAcceleratorTable, CreateMenu, ACCEL};
 
         let hash = new.get_hash();
         let mut root = unsafe { CreateMenu() };
         let mut command_map = BTreeMap::new();
+        let mut accels: Vec<ACCEL> = Vec::new();
 
-        Self::recursive_construct_menu(&mut root, new.items.as_ref(), &mut command_map);
+        Self::recursive_construct_menu(
+            &mut root,
+            new.items.as_ref(),
+            &mut command_map,
+            &mut accels,
+        );
+
+        // Build a single accelerator table for the whole menu; an empty table
+        // would just be overhead, so only create one when there are bindings.
+        let accel = if accels.is_empty() {
+            None
+        } else {
+            let table = unsafe {
+                CreateAcceleratorTable(accels.as_mut_ptr(), accels.len() as i32)
+            };
+            if table.is_null() {
+                None
+            } else {
+                Some(table)
+            }
+        };
 
         Self {
             _native_ptr: root,
             callbacks: command_map,
+            accel,
             hash,
         }
     }
 
-    fn get_new_command_id() -> usize {
-        WINDOWS_UNIQUE_COMMAND_ID_GENERATOR.fetch_add(1, AtomicOrdering::SeqCst)
+    /// Returns a fresh, non-zero `WM_COMMAND` id.
+    ///
+    /// `WINDOWS_UNIQUE_COMMAND_ID_GENERATOR` is a single process-wide counter
+    /// shared by every menu bar and every context-menu popup ever built, so a
+    /// long-running window that repeatedly opens context menus can run it
+    /// well past `u16::MAX` (`WM_COMMAND` ids are 16-bit). Wrap back into
+    /// `1..=u16::MAX` instead of saturating at `u16::MAX`: saturating would
+    /// have every command built after the 65535th collide on the single id
+    /// `u16::MAX`, so every one of them would silently invoke whichever
+    /// callback got inserted there last instead of its own.
+    fn get_new_command_id() -> u16 {
+        let id = WINDOWS_UNIQUE_COMMAND_ID_GENERATOR.fetch_add(1, AtomicOrdering::SeqCst);
+        (1 + (id % (core::u16::MAX as usize))) as u16
     }
 
     fn recursive_construct_menu(
         menu: &mut HMENU,
         items: &[MenuItem],
         command_map: &mut BTreeMap<u16, MenuCallback>,
+        accels: &mut Vec<winapi::um::winuser::ACCEL>,
     ) {
         fn convert_widestring(input: &str) -> Vec<u16> {
             let mut v: Vec<u16> = input
@@ -2336,7 +5335,7 @@ impl WindowsMenuBar {
         }
 
         use winapi::shared::basetsd::UINT_PTR;
-        use winapi::um::winuser::{AppendMenuW, CreateMenu};
+        use winapi::um::winuser::{AppendMenuW, CreateMenu, ACCEL};
         use winapi::um::winuser::{MF_MENUBREAK, MF_POPUP, MF_SEPARATOR, MF_STRING};
 
         for item in items.as_ref() {
@@ -2347,18 +5346,37 @@ impl WindowsMenuBar {
                         let command = match mi.callback.as_ref() {
                             None => 0,
                             Some(c) => {
-                                let new_command_id =
-                                    Self::get_new_command_id().min(core::u16::MAX as usize) as u16;
+                                let new_command_id = Self::get_new_command_id();
                                 command_map.insert(new_command_id, c.clone());
                                 new_command_id as usize
                             }
                         };
+
+                        // A parseable accelerator on a real (command-bearing)
+                        // item becomes an `ACCEL` entry keyed by the command ID
+                        // and is shown right-aligned after a tab in the label.
+                        let mut label = mi.label.as_str().to_string();
+                        if let Some(shortcut) = mi.accelerator.as_ref() {
+                            let shortcut = shortcut.as_str();
+                            if command != 0 {
+                                if let Some((fvirt, key)) = parse_accelerator(shortcut) {
+                                    accels.push(ACCEL {
+                                        fVirt: fvirt,
+                                        key,
+                                        cmd: command as u16,
+                                    });
+                                    label.push('\t');
+                                    label.push_str(shortcut);
+                                }
+                            }
+                        }
+
                         unsafe {
                             AppendMenuW(
                                 *menu,
                                 MF_STRING,
                                 command,
-                                convert_widestring(mi.label.as_str()).as_ptr(),
+                                convert_widestring(&label).as_ptr(),
                             )
                         };
                     } else {
@@ -2367,6 +5385,7 @@ impl WindowsMenuBar {
                             &mut root,
                             mi.children.as_ref(),
                             command_map,
+                            accels,
                         );
                         unsafe {
                             AppendMenuW(
@@ -2402,11 +5421,13 @@ unsafe extern "system" fn WindowProc(
         WM_NCCREATE, WM_TIMER, WM_COMMAND,
         WM_CREATE, WM_NCMOUSELEAVE, WM_ERASEBKGND,
         WM_MOUSEMOVE, WM_DESTROY, WM_PAINT, WM_ACTIVATE,
-        WM_MOUSEWHEEL, WM_SIZE, WM_NCHITTEST,
+        WM_MOUSEWHEEL, WM_MOUSEHWHEEL, WM_SIZE, WM_NCHITTEST,
         WM_LBUTTONDOWN, WM_DPICHANGED, WM_RBUTTONDOWN,
         WM_LBUTTONUP, WM_RBUTTONUP, WM_MOUSELEAVE,
         WM_DISPLAYCHANGE, WM_SIZING, WM_WINDOWPOSCHANGED,
-        WM_QUIT, WM_HSCROLL, WM_VSCROLL,
+        WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_SETTINGCHANGE,
+        WM_KEYDOWN, WM_SYSKEYDOWN,
+        WHEEL_DELTA,
 
         CREATESTRUCTW, GWLP_USERDATA,
     };
@@ -2571,7 +5592,7 @@ unsafe extern "system" fn WindowProc(
                     },
                 };
 
-                create_windows(ab, new_windows);
+                create_windows(ab, shared_application_data, new_windows);
                 destroy_windows(ab, destroyed_windows);
 
                 match ret {
@@ -2620,11 +5641,7 @@ unsafe extern "system" fn WindowProc(
                         current_window.render_api.request_hit_tester(wr_document_id)
                     );
 
-                    generate_frame(
-                        &mut current_window.internal,
-                        &mut current_window.render_api,
-                        true,
-                    );
+                    current_window.request_frame(true);
 
                     InvalidateRect(current_window.hwnd, ptr::null_mut(), 0);
                     mem::drop(app_borrow);
@@ -2642,11 +5659,7 @@ unsafe extern "system" fn WindowProc(
 
                 match app_borrow.windows.get_mut(&hwnd_key) {
                     Some(current_window) => {
-                        generate_frame(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            false,
-                        );
+                        current_window.request_frame(false);
 
                         InvalidateRect(current_window.hwnd, ptr::null_mut(), 0);
                     },
@@ -2656,11 +5669,79 @@ unsafe extern "system" fn WindowProc(
                 mem::drop(app_borrow);
                 return DefWindowProcW(hwnd, msg, wparam, lparam);
             },
+            AZ_COMPOSITE_NEEDED => {
+
+                // Posted by `Notifier` once WebRender finished building a
+                // frame on its own thread; just invalidate so the next
+                // `WM_PAINT` composites it instead of waiting for the next
+                // input event to pump the message loop.
+                use winapi::um::winuser::InvalidateRect;
+
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    InvalidateRect(current_window.hwnd, ptr::null_mut(), 0);
+                }
+
+                mem::drop(app_borrow);
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            },
             WM_CREATE => {
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
             WM_ACTIVATE => {
+                use winapi::um::winuser::WA_INACTIVE;
+                use winapi::shared::minwindef::LOWORD;
+
+                // A confined cursor is grabbed with `ClipCursor` and a hidden
+                // cursor holds an outstanding `ShowCursor(FALSE)`; the OS
+                // releases the clip whenever the window is deactivated. Mirror
+                // that: drop the clip / unhide on the way out so other windows
+                // aren't affected, and re-arm the mode once we're active again
+                // (the `WM_MOUSEMOVE` path re-applies it once the cursor is back
+                // over the client area as well).
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    if LOWORD(wparam as u32) as u32 == WA_INACTIVE {
+                        current_window.leave_cursor_mode();
+                    } else {
+                        current_window.enter_cursor_mode();
+                    }
+                }
+
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_SETTINGCHANGE => {
+                // The system broadcasts this with lParam pointing at the wide
+                // string "ImmersiveColorSet" when the user flips the light /
+                // dark setting. Re-derive the window theme from the registry,
+                // re-apply the non-client dark mode and regenerate the DOM so
+                // styling that depends on the theme picks up the change.
+                let is_color_set = if lparam == 0 {
+                    false
+                } else {
+                    // `encode_wide` already appends the terminating NUL, so a
+                    // full element-wise match also checks string length.
+                    let needle = encode_wide("ImmersiveColorSet");
+                    let ptr = lparam as *const u16;
+                    needle.iter().enumerate().all(|(i, &n)| *ptr.add(i) == n)
+                };
+
+                if is_color_set {
+                    let dark = system_prefers_dark_mode();
+                    let new_theme = if dark { WindowTheme::DarkMode } else { WindowTheme::LightMode };
+                    if let Some(dwm) = app_borrow.dwm.as_ref() {
+                        dwm.set_dark_mode(hwnd, dark);
+                    }
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        if current_window.internal.current_window_state.theme != new_theme {
+                            let previous_state = current_window.internal.current_window_state.clone();
+                            current_window.internal.previous_window_state = Some(previous_state);
+                            current_window.internal.current_window_state.theme = new_theme;
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    }
+                }
+
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
@@ -2728,6 +5809,18 @@ unsafe extern "system" fn WindowProc(
                         SetClassLongPtrW(current_window.hwnd, GCLP_HCURSOR, win32_translate_cursor(cht.cursor_icon) as isize);
                     }
 
+                    // Pick up the requested cursor mode from the window state
+                    // and (re-)apply it: confinement is lost whenever focus is
+                    // lost, so the first move back over the client area
+                    // reinstates the clip / hide.
+                    current_window.cursor_mode =
+                        if current_window.internal.current_window_state.flags.is_cursor_locked {
+                            CursorMode::Confined
+                        } else {
+                            current_window.cursor_mode
+                        };
+                    current_window.enter_cursor_mode();
+
                     PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 };
 
@@ -2750,6 +5843,11 @@ unsafe extern "system" fn WindowProc(
                     current_window.internal.current_window_state.last_hit_test = FullHitTest::empty(current_focus);
                     current_window.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
 
+                    // Cursor left the client area: unhide it / drop the clip so
+                    // it behaves normally over other windows. The mode is kept
+                    // and re-armed the next time the cursor re-enters.
+                    current_window.leave_cursor_mode();
+
                     SetClassLongPtrW(hwnd, GCLP_HCURSOR, win32_translate_cursor(MouseCursorType::Default) as isize);
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                     mem::drop(app_borrow);
@@ -2766,31 +5864,20 @@ unsafe extern "system" fn WindowProc(
                     current_window.internal.current_window_state.mouse_state.right_down = true;
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
-                /*
-                use winapi::um::winuser::{
-                    CreatePopupMenu, InsertMenuW, TrackPopupMenu, SetForegroundWindow,
-                    GetCursorPos,
-                    MF_BYPOSITION, MF_STRING, TPM_TOPALIGN, TPM_LEFTALIGN
-                };
-                use winapi::shared::windef::POINT;
-                let mut pos: POINT = POINT { x: 0, y: 0 };
-                GetCursorPos(&mut pos);
-                let hPopupMenu = CreatePopupMenu();
-                let mut a = encode_wide("Exit");
-                let mut b = encode_wide("Play");
-                InsertMenuW(hPopupMenu, 0, MF_BYPOSITION | MF_STRING, 0, a.as_mut_ptr());
-                InsertMenuW(hPopupMenu, 0, MF_BYPOSITION | MF_STRING, 0, b.as_mut_ptr());
-                SetForegroundWindow(hwnd);
-                TrackPopupMenu(hPopupMenu, TPM_TOPALIGN | TPM_LEFTALIGN, pos.x, pos.y, 0, hwnd, ptr::null_mut())
-                */
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
             WM_RBUTTONUP => {
+                // On release, open the native context menu of the node under the
+                // cursor (if any) before routing the button-up through the hit
+                // test. The popup is command-driven: `TrackPopupMenu` posts a
+                // `WM_COMMAND` for the selection, which looks the id up in the
+                // window's `context_menu_callbacks` and runs the callback.
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     let previous_state = current_window.internal.current_window_state.clone();
                     current_window.internal.previous_window_state = Some(previous_state);
                     current_window.internal.current_window_state.mouse_state.right_down = false;
+                    show_context_menu(hwnd, current_window);
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
                 }
                 mem::drop(app_borrow);
@@ -2817,35 +5904,172 @@ unsafe extern "system" fn WindowProc(
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
             WM_MOUSEWHEEL => {
-                println!("WM_MOUSEWHEEL!");
+                use winapi::um::winuser::GET_WHEEL_DELTA_WPARAM;
+
+                // The high word of wParam is a signed WHEEL_DELTA multiple: a
+                // positive value means scrolling away from the user (content
+                // moves up). Normalize to notches, scale by the user's
+                // lines-per-notch setting, and feed the vertical scroll delta
+                // into the mouse state. The hit-test pass routes it through
+                // `process_system_scroll` / `do_system_scroll`, which finds the
+                // topmost scrollable frame under the cursor, clamps to the
+                // content bounds and re-renders on the GPU via
+                // `AZ_GPU_SCROLL_RENDER`.
+                let notches = GET_WHEEL_DELTA_WPARAM(wparam) as f32 / WHEEL_DELTA as f32;
+
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.mouse_state.scroll_y =
+                        Some(notches * wheel_scroll_pixels_per_notch()).into();
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
 
-                    let scroll_frames = current_window.internal.current_window_state.last_hit_test.hovered_nodes.iter()
-                    .filter_map(|(dom_id, hit_test)| {
-                        if !hit_test.scroll_hit_test_nodes.is_empty() {
-                            Some((dom_id, hit_test.scroll_hit_test_nodes.clone()))
-                        } else {
-                            None
-                        }
-                    }).collect::<BTreeMap<_, _>>();
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_MOUSEHWHEEL => {
+                use winapi::um::winuser::GET_WHEEL_DELTA_WPARAM;
+
+                // Horizontal wheel / tilt: a positive delta scrolls the content
+                // to the right. Same routing as the vertical wheel above.
+                let notches = GET_WHEEL_DELTA_WPARAM(wparam) as f32 / WHEEL_DELTA as f32;
 
-                    println!("current scroll frames: {:#?}", scroll_frames);
-                }
-                /*
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     let previous_state = current_window.internal.current_window_state.clone();
                     current_window.internal.previous_window_state = Some(previous_state);
-                    current_window.internal.current_window_state.mouse_state. ;
-                    // left_down = false;
+                    current_window.internal.current_window_state.mouse_state.scroll_x =
+                        Some(-notches * wheel_scroll_pixels_per_notch()).into();
                     PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
-                }*/
+                }
+
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
-            WM_DPICHANGED => {
+            WM_HSCROLL => {
+                // Native horizontal scrollbar: translate the scroll-bar request
+                // code into a horizontal delta and route it through the same
+                // system-scroll path as the wheel.
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    if let Some(delta) = scrollbar_request_to_delta(wparam) {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.mouse_state.scroll_x =
+                            Some(delta).into();
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_VSCROLL => {
+                // Native vertical scrollbar, same path as `WM_HSCROLL`.
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    if let Some(delta) = scrollbar_request_to_delta(wparam) {
+                        let previous_state = current_window.internal.current_window_state.clone();
+                        current_window.internal.previous_window_state = Some(previous_state);
+                        current_window.internal.current_window_state.mouse_state.scroll_y =
+                            Some(delta).into();
+                        PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
+            WM_DPICHANGED => {
+                use azul_core::window::PhysicalSize;
+                use winapi::shared::minwindef::HIWORD;
+                use winapi::um::winuser::{SetWindowPos, SWP_NOZORDER, SWP_NOACTIVATE};
+
+                // The high word of wParam is the new DPI (both axes are equal).
+                // lParam points at the rectangle Windows recommends the window
+                // move/resize to so it keeps the same physical size on the new
+                // monitor; honoring it avoids a second WM_DPICHANGED bounce.
+                let new_dpi = HIWORD(wparam as u32) as u32;
+                let new_hidpi_factor = new_dpi as f32 / 96.0;
+
+                let suggested = lparam as *const RECT;
+                let (new_width, new_height) = if suggested.is_null() {
+                    (0u16, 0u16)
+                } else {
+                    let r = &*suggested;
+                    SetWindowPos(
+                        hwnd,
+                        ptr::null_mut(),
+                        r.left,
+                        r.top,
+                        r.right - r.left,
+                        r.bottom - r.top,
+                        SWP_NOZORDER | SWP_NOACTIVATE,
+                    );
+                    (
+                        (r.right - r.left).max(0) as u16,
+                        (r.bottom - r.top).max(0) as u16,
+                    )
+                };
+
+                let new_size = PhysicalSize {
+                    width: new_width as u32,
+                    height: new_height as u32,
+                };
+
+                let mut ab = &mut *app_borrow;
+                let fc_cache = &mut ab.fc_cache;
+                let windows = &mut ab.windows;
+                let image_cache = &ab.image_cache;
+
+                if let Some(current_window) = windows.get_mut(&hwnd_key) {
+                    fc_cache.apply_closure(|fc_cache| {
+                        let mut new_window_state = current_window.internal.current_window_state.clone();
+                        new_window_state.size.dpi = new_dpi;
+                        new_window_state.size.hidpi_factor = new_hidpi_factor;
+                        new_window_state.size.system_hidpi_factor = new_hidpi_factor;
+                        new_window_state.size.dimensions = new_size.to_logical(new_hidpi_factor);
+
+                        current_window.internal.do_quick_resize(
+                            &image_cache,
+                            &crate::app::CALLBACKS,
+                            azul_layout::do_the_relayout,
+                            fc_cache,
+                            &new_window_state.size,
+                            new_window_state.theme,
+                        );
+
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state = new_window_state;
+
+                        let mut txn = WrTransaction::new();
+                        txn.set_document_view(
+                            WrDeviceIntRect::from_size(
+                                WrDeviceIntSize::new(new_width as i32, new_height as i32),
+                            )
+                        );
+                        current_window.render_api.send_transaction(wr_translate_document_id(current_window.internal.document_id), txn);
+
+                        rebuild_display_list(
+                            &mut current_window.internal,
+                            &mut current_window.render_api,
+                            image_cache,
+                            Vec::new(),
+                        );
+
+                        let wr_document_id = wr_translate_document_id(current_window.internal.document_id);
+                        current_window.hit_tester = AsyncHitTester::Requested(
+                            current_window.render_api.request_hit_tester(wr_document_id)
+                        );
+
+                        current_window.request_frame(true);
+                    });
+
+                    mem::drop(app_borrow);
+                    return 0;
+                } else {
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+            },
             WM_SIZE => {
                 use azul_core::window::{WindowFrame, PhysicalSize};
                 use winapi::um::winuser::{
@@ -2916,11 +6140,7 @@ unsafe extern "system" fn WindowProc(
                             current_window.render_api.request_hit_tester(wr_document_id)
                         );
 
-                        generate_frame(
-                            &mut current_window.internal,
-                            &mut current_window.render_api,
-                            true,
-                        );
+                        current_window.request_frame(true);
                     });
 
                     mem::drop(app_borrow);
@@ -2931,15 +6151,99 @@ unsafe extern "system" fn WindowProc(
                 }
             },
             WM_NCHITTEST => {
+                use winapi::um::winuser::{
+                    GetWindowRect,
+                    HTLEFT, HTRIGHT, HTTOP, HTBOTTOM, HTTOPLEFT, HTTOPRIGHT,
+                    HTBOTTOMLEFT, HTBOTTOMRIGHT, HTCAPTION, HTCLIENT,
+                };
+                use winapi::shared::windowsx::{GET_X_LPARAM, GET_Y_LPARAM};
+                use azul_core::window::LogicalPosition;
+
+                // Only borderless windows need synthetic hit testing; decorated
+                // windows let the default non-client frame handle resize/move.
+                let borderless = app_borrow
+                    .windows
+                    .get(&hwnd_key)
+                    .map(|w| !w.internal.current_window_state.flags.has_decorations)
+                    .unwrap_or(false);
+
+                if !borderless {
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                let resizable = app_borrow
+                    .windows
+                    .get(&hwnd_key)
+                    .map(|w| w.internal.current_window_state.flags.is_resizable)
+                    .unwrap_or(false);
+                let hidpi_factor = app_borrow
+                    .windows
+                    .get(&hwnd_key)
+                    .map(|w| w.internal.current_window_state.size.hidpi_factor)
+                    .unwrap_or(1.0);
+
+                // lParam is in screen coordinates; translate into window-local.
+                let screen_x = GET_X_LPARAM(lparam);
+                let screen_y = GET_Y_LPARAM(lparam);
+                let mut rect: RECT = mem::zeroed();
+                GetWindowRect(hwnd, &mut rect);
+                let local_x = screen_x - rect.left;
+                let local_y = screen_y - rect.top;
+                let width = rect.right - rect.left;
+                let height = rect.bottom - rect.top;
+
+                // Resize border inset, scaled to the window's DPI so it stays a
+                // constant physical size.
+                let border = (6.0 * hidpi_factor).round() as i32;
+
+                if resizable {
+                    let on_left = local_x < border;
+                    let on_right = local_x >= width - border;
+                    let on_top = local_y < border;
+                    let on_bottom = local_y >= height - border;
+
+                    let ht = match (on_top, on_bottom, on_left, on_right) {
+                        (true, _, true, _) => Some(HTTOPLEFT),
+                        (true, _, _, true) => Some(HTTOPRIGHT),
+                        (_, true, true, _) => Some(HTBOTTOMLEFT),
+                        (_, true, _, true) => Some(HTBOTTOMRIGHT),
+                        (true, _, _, _) => Some(HTTOP),
+                        (_, true, _, _) => Some(HTBOTTOM),
+                        (_, _, true, _) => Some(HTLEFT),
+                        (_, _, _, true) => Some(HTRIGHT),
+                        _ => None,
+                    };
+
+                    if let Some(code) = ht {
+                        mem::drop(app_borrow);
+                        return code as LRESULT;
+                    }
+                }
+
+                // Not on a resize border: the caption strip along the top edge
+                // moves the window (see `cursor_over_drag_region`).
+                let over_drag_region = app_borrow
+                    .windows
+                    .get(&hwnd_key)
+                    .map(|w| {
+                        let pos = LogicalPosition::new(
+                            local_x as f32 / hidpi_factor,
+                            local_y as f32 / hidpi_factor,
+                        );
+                        cursor_over_drag_region(w, pos)
+                    })
+                    .unwrap_or(false);
+
                 mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
+                if over_drag_region {
+                    return HTCAPTION as LRESULT;
+                }
+                return HTCLIENT as LRESULT;
             },
             WM_PAINT => {
 
-                use winapi::um::{
-                    wingdi::SwapBuffers,
-                    winuser::{GetDC, ReleaseDC, GetClientRect},
-                };
+                use winapi::um::winuser::{GetDC, ReleaseDC, GetClientRect, InvalidateRect};
 
                 // Assuming that the display list has been submitted and the
                 // scene on the background thread has been rebuilt, now tell
@@ -2961,66 +6265,97 @@ unsafe extern "system" fn WindowProc(
                     },
                 };
 
-                let gl_context = match current_window.gl_context {
-                    Some(s) => s,
-                    None => {
-                        // TODO: software rendering
-                        mem::drop(app_borrow);
-                        return DefWindowProcW(hwnd, msg, wparam, lparam);
-                    },
-                };
-
-                wglMakeCurrent(hDC, gl_context);
-
                 let mut rect: RECT = mem::zeroed();
                 GetClientRect(hwnd, &mut rect);
 
-                // Block until all transactions (display list build)
-                // have finished processing
+                let framebuffer_size = WrDeviceIntSize::new(
+                    rect.width() as i32,
+                    rect.height() as i32
+                );
+
+                // Block until all transactions (display list build) have
+                // finished processing. This is identical for the hardware and
+                // software paths; only the presentation step differs.
                 //
                 // Usually this shouldn't take too long, since DL building
                 // happens asynchronously between WM_SIZE and WM_PAINT
                 current_window.render_api.flush_scene_builder();
 
-                let mut gl = &mut current_window.gl_functions.functions;
-
-                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                gl.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                gl.disable(gl_context_loader::gl::MULTISAMPLE);
-                gl.viewport(0, 0, rect.width() as i32, rect.height() as i32);
+                match current_window.gl_context {
+                    Some(gl_context) => {
+                        if !gl_context.make_current(hDC) {
+                            // Context lost (GPU reset, driver update, dGPU/iGPU
+                            // switch...): tear it down and rebuild rather than
+                            // drawing into a dead context or leaving the window
+                            // permanently blank. A failed recovery falls back
+                            // to the software path on the *next* WM_PAINT, once
+                            // `gl_context` has been cleared to `None` below.
+                            if !current_window.recover_lost_gl_context(shared_application_data) {
+                                current_window.gl_context = None;
+                            }
+                            ReleaseDC(hwnd, hDC);
+                            mem::drop(app_borrow);
+                            InvalidateRect(hwnd, ptr::null_mut(), 0);
+                            return DefWindowProcW(hwnd, msg, wparam, lparam);
+                        }
 
-                let mut current_program = [0_i32];
-                gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+                        let mut gl = &mut current_window.gl_functions.functions;
+
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+                        gl.disable(gl_context_loader::gl::MULTISAMPLE);
+                        gl.viewport(0, 0, rect.width() as i32, rect.height() as i32);
+
+                        let mut current_program = [0_i32];
+                        gl.get_integer_v(gl_context_loader::gl::CURRENT_PROGRAM, (&mut current_program[..]).into());
+
+                        // Render
+                        if let Some(r) = current_window.renderer.as_mut() {
+                            r.update();
+                            let _ = r.render(framebuffer_size, 0);
+                            let pipeline_info = r.flush_pipeline_info();
+                            if !pipeline_info.epochs.is_empty() {
+                                // delete unused external OpenGL texture
+                                use crate::wr_translate::translate_epoch_wr;
+
+                                let oldest_to_remove_epoch = pipeline_info.epochs.values().min().unwrap();
+                                azul_core::gl::gl_textures_remove_epochs_from_pipeline(
+                                    &current_window.internal.document_id,
+                                    translate_epoch_wr(*oldest_to_remove_epoch)
+                                );
+                            }
+                        }
 
-                let framebuffer_size = WrDeviceIntSize::new(
-                    rect.width() as i32,
-                    rect.height() as i32
-                );
+                        let swapped = gl_context.swap_buffers(hDC);
 
-                // Render
-                if let Some(r) = current_window.renderer.as_mut() {
-                    r.update();
-                    let _ = r.render(framebuffer_size, 0);
-                    let pipeline_info = r.flush_pipeline_info();
-                    if !pipeline_info.epochs.is_empty() {
-                        // delete unused external OpenGL texture
-                        use crate::wr_translate::translate_epoch_wr;
-
-                        let oldest_to_remove_epoch = pipeline_info.epochs.values().min().unwrap();
-                        azul_core::gl::gl_textures_remove_epochs_from_pipeline(
-                            &current_window.internal.document_id,
-                            translate_epoch_wr(*oldest_to_remove_epoch)
-                        );
-                    }
-                }
+                        gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+                        gl.use_program(current_program[0] as u32);
 
-                SwapBuffers(hDC);
+                        gl_context.release_current();
 
-                gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                gl.use_program(current_program[0] as u32);
+                        if !swapped {
+                            // Same recovery as a failed `make_current` above,
+                            // just caught one step later in the present call.
+                            if !current_window.recover_lost_gl_context(shared_application_data) {
+                                current_window.gl_context = None;
+                            }
+                            ReleaseDC(hwnd, hDC);
+                            mem::drop(app_borrow);
+                            InvalidateRect(hwnd, ptr::null_mut(), 0);
+                            return DefWindowProcW(hwnd, msg, wparam, lparam);
+                        }
+                    },
+                    None => {
+                        // No usable WGL/EGL context: render with the window's
+                        // software GL backend into an off-screen RGBA buffer and
+                        // blit it to the `hDC` with `SetDIBitsToDevice`. The
+                        // `flush_scene_builder` / epoch-cleanup steps above and
+                        // below are shared, so only presentation differs.
+                        software_blit_scene(current_window, hDC, &rect, framebuffer_size);
+                    },
+                }
 
-                wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
                 ReleaseDC(hwnd, hDC);
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
@@ -3029,7 +6364,7 @@ unsafe extern "system" fn WindowProc(
                 match wparam {
                     AZ_THREAD_TICK => {
                         // tick every 16ms to process new thread messages
-                        run_all_threads();
+                        run_all_threads(&mut *app_borrow);
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam);
                     },
@@ -3073,7 +6408,7 @@ unsafe extern "system" fn WindowProc(
                             },
                         }
 
-                        create_windows(ab, new_windows);
+                        create_windows(ab, shared_application_data, new_windows);
                         destroy_windows(ab, destroyed_windows);
 
                         match ret {
@@ -3097,66 +6432,677 @@ unsafe extern "system" fn WindowProc(
                             },
                         }
 
-                        mem::drop(app_borrow);
-                        return 0;
-                    }
-                }
-            },
-            WM_COMMAND => {
-                // execute menu callback
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_QUIT => {
-                // TODO: execute quit callback
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            WM_DESTROY => {
+                        mem::drop(app_borrow);
+                        return 0;
+                    }
+                }
+            },
+            WM_KEYDOWN | WM_SYSKEYDOWN => {
+                use winapi::um::winuser::{
+                    FALT, FCONTROL, FSHIFT, FVIRTKEY, GetKeyState, VK_CONTROL, VK_MENU, VK_SHIFT,
+                };
+
+                // Build the same `fVirt` mask the accelerator table is keyed by
+                // from the current modifier state, then look the pressed key up.
+                // The high bit of `GetKeyState` marks a key as currently down.
+                let mut fvirt = FVIRTKEY;
+                if GetKeyState(VK_CONTROL) < 0 { fvirt |= FCONTROL; }
+                if GetKeyState(VK_MENU) < 0 { fvirt |= FALT; }
+                if GetKeyState(VK_SHIFT) < 0 { fvirt |= FSHIFT; }
+                let vk = wparam as u16;
+
+                let accel_callback = app_borrow
+                    .windows
+                    .get(&hwnd_key)
+                    .and_then(|w| w.accelerators.get(&(fvirt, vk)).cloned());
+
+                let accel_callback = match accel_callback {
+                    Some(c) => c,
+                    None => {
+                        // Not a registered shortcut: fall through to normal DOM
+                        // key dispatch / default handling.
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    }
+                };
+
+                let mut ret = ProcessEventResult::DoNothing;
+                let cur_hwnd;
+
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let image_cache = &mut ab.image_cache;
+                let config = &ab.config;
+                let hinstance = ab.hinstance;
+
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
+
+                match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        cur_hwnd = current_window.hwnd;
+                        ret = process_menu_command(
+                            hinstance,
+                            current_window,
+                            &accel_callback,
+                            fc_cache,
+                            image_cache,
+                            config,
+                            &mut new_windows,
+                            &mut destroyed_windows,
+                        );
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                }
+
+                create_windows(ab, shared_application_data, new_windows);
+                destroy_windows(ab, destroyed_windows);
+
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in app_borrow.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        PostMessageW(cur_hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
+                }
+
+                mem::drop(app_borrow);
+                return 0;
+            },
+            WM_COMMAND => {
+                use winapi::shared::minwindef::{LOWORD, HIWORD};
+
+                // A menu selection arrives with a null lParam and the notify code
+                // (HIWORD) set to 0; anything else is an accelerator (1) or a
+                // control notification, which we don't handle here.
+                let command_id = LOWORD(wparam as u32);
+                let is_menu_command = lparam == 0 && HIWORD(wparam as u32) <= 1;
+
+                if !is_menu_command {
+                    mem::drop(app_borrow);
+                    return DefWindowProcW(hwnd, msg, wparam, lparam);
+                }
+
+                let mut ret = ProcessEventResult::DoNothing;
+                let cur_hwnd;
+
+                let ab = &mut *app_borrow;
+                let windows = &mut ab.windows;
+                let fc_cache = &mut ab.fc_cache;
+                let image_cache = &mut ab.image_cache;
+                let config = &ab.config;
+                let hinstance = ab.hinstance;
+
+                let mut new_windows = Vec::new();
+                let mut destroyed_windows = Vec::new();
+
+                match windows.get_mut(&hwnd_key) {
+                    Some(current_window) => {
+                        cur_hwnd = current_window.hwnd;
+
+                        // Menu-bar items and the active context menu share the
+                        // command-id space; check both maps for the callback.
+                        let menu_callback = current_window
+                            .menu_callbacks
+                            .get(&command_id)
+                            .or_else(|| current_window.context_menu_callbacks.get(&command_id))
+                            .cloned();
+
+                        if let Some(menu_callback) = menu_callback {
+                            ret = process_menu_command(
+                                hinstance,
+                                current_window,
+                                &menu_callback,
+                                fc_cache,
+                                image_cache,
+                                config,
+                                &mut new_windows,
+                                &mut destroyed_windows,
+                            );
+                        }
+                    },
+                    None => {
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam);
+                    },
+                }
+
+                create_windows(ab, shared_application_data, new_windows);
+                destroy_windows(ab, destroyed_windows);
+
+                match ret {
+                    ProcessEventResult::DoNothing => { },
+                    ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DOM, 0, 0);
+                    },
+                    ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                        for window in app_borrow.windows.values() {
+                            PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+                    },
+                    ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+                    },
+                    ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                        PostMessageW(cur_hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    },
+                    ProcessEventResult::ShouldReRenderCurrentWindow => {
+                        PostMessageW(cur_hwnd, AZ_GPU_SCROLL_RENDER, 0, 0);
+                    },
+                }
+
+                mem::drop(app_borrow);
+                return 0;
+            },
+            WM_QUIT => {
+                // TODO: execute quit callback
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_DESTROY => {
+
+                let windows_is_emtpy = {
+                    let mut app = &mut *app_borrow;
+                    let _ = app.windows.remove(&(hwnd as usize));
+                    app.windows.is_empty()
+                };
+
+                // destruct the window data
+                let mut window_data = Box::from_raw(GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *mut SharedApplicationData);
+                mem::drop(window_data);
+                mem::drop(app_borrow);
+                if windows_is_emtpy {
+                    PostQuitMessage(0);
+                }
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            _ => {
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            }
+        }
+    };
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ProcessEventResult {
+    DoNothing,
+    ShouldRegenerateDomCurrentWindow,
+    ShouldRegenerateDomAllWindows,
+    ShouldUpdateDisplayListCurrentWindow,
+    // GPU transforms changed: do another hit-test and recurse
+    // until nothing has changed anymore
+    UpdateHitTesterAndProcessAgain,
+    // Only refresh the display (in case of pure scroll or GPU-only events)
+    ShouldReRenderCurrentWindow,
+}
+
+/// Software-rendering presentation path, used when a window has no usable GL
+/// context. Renders the current WebRender scene with the window's software GL
+/// backend into its off-screen framebuffer, reads the pixels back and blits
+/// them to `hDC` with `SetDIBitsToDevice`. swGL writes a top-down BGRA buffer,
+/// which is exactly what a 32-bit `BI_RGB` DIB with a negative `biHeight`
+/// wants, so the copy needs no format conversion or vertical flip.
+unsafe fn software_blit_scene(
+    window: &mut Window,
+    hDC: HDC,
+    rect: &RECT,
+    framebuffer_size: WrDeviceIntSize,
+) {
+    use gl_context_loader::gl;
+    use winapi::um::wingdi::{
+        SetDIBitsToDevice, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    };
+
+    let width = rect.width() as i32;
+    let height = rect.height() as i32;
+    if width <= 0 || height <= 0 {
+        return;
+    }
+
+    {
+        let glfns = &mut window.gl_functions.functions;
+        glfns.bind_framebuffer(gl::FRAMEBUFFER, 0);
+        glfns.viewport(0, 0, width, height);
+    }
+
+    if let Some(r) = window.renderer.as_mut() {
+        r.update();
+        let _ = r.render(framebuffer_size, 0);
+        let pipeline_info = r.flush_pipeline_info();
+        if !pipeline_info.epochs.is_empty() {
+            use crate::wr_translate::translate_epoch_wr;
+            let oldest_to_remove_epoch = pipeline_info.epochs.values().min().unwrap();
+            azul_core::gl::gl_textures_remove_epochs_from_pipeline(
+                &window.internal.document_id,
+                translate_epoch_wr(*oldest_to_remove_epoch),
+            );
+        }
+    }
+
+    let pixels = window
+        .gl_functions
+        .functions
+        .read_pixels(0, 0, width, height, gl::BGRA, gl::UNSIGNED_BYTE);
+    if pixels.is_empty() {
+        return;
+    }
+
+    let mut info: BITMAPINFO = mem::zeroed();
+    info.bmiHeader = BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+        biWidth: width,
+        // Negative height = top-down DIB, matching GDI's top-left origin.
+        biHeight: -height,
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB,
+        biSizeImage: 0,
+        biXPelsPerMeter: 0,
+        biYPelsPerMeter: 0,
+        biClrUsed: 0,
+        biClrImportant: 0,
+    };
+
+    SetDIBitsToDevice(
+        hDC,
+        0, 0,
+        width as u32, height as u32,
+        0, 0,
+        0, height as u32,
+        pixels.as_ptr() as *const _,
+        &info,
+        DIB_RGB_COLORS,
+    );
+}
+
+// Assuming that current_window_state and the previous_window_state of the window
+// are set correctly and the hit-test has been performed, will call the callbacks
+// and return what the application should do next
+#[must_use]
+fn process_event(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>,
+) -> ProcessEventResult {
+
+    use azul_core::window_state::{
+        Events, NodesToCheck, CallbacksOfHitTest,
+        StyleAndLayoutChanges,
+    };
+    use azul_core::window::FullWindowState;
+    use azul_core::callbacks::Update;
+
+    // TODO:
+    // window.internal.current_window_state.monitor =
+    // win32_translate_monitor(MonitorFromWindow(window.hwnd, MONITOR_DEFAULTTONEAREST));
+
+    // Get events
+    let events = Events::new(
+        &window.internal.current_window_state,
+        &window.internal.previous_window_state,
+    );
+
+    // Get nodes for events
+    let nodes_to_check = NodesToCheck::new(
+        &window.internal.current_window_state.last_hit_test,
+        &events
+    );
+
+    // Invoke callbacks on nodes
+    let mut callback_results = fc_cache.apply_closure(|fc_cache| {
+
+        use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+        // Get callbacks for nodes
+        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+        let current_scroll_states = window.internal.get_current_scroll_states();
+
+        // Invoke user-defined callbacks in the UI
+        callbacks.call(
+            &window.internal.previous_window_state,
+            &window.internal.current_window_state,
+            &window_handle,
+            &current_scroll_states,
+            &window.gl_context_ptr,
+            &mut window.internal.layout_results,
+            &mut window.internal.scroll_states,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    window.start_stop_timers(
+        callback_results.timers.unwrap_or_default(),
+        callback_results.timers_removed.unwrap_or_default()
+    );
+    window.start_stop_threads(
+        callback_results.threads.unwrap_or_default(),
+        callback_results.threads_removed.unwrap_or_default()
+    );
+
+    for w in callback_results.windows_created {
+        new_windows.push(w);
+    }
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
+    let need_scroll_render = scroll.is_some();
+
+    if let Some(modified) = callback_results.modified_window_state.as_ref() {
+        if modified.flags.is_about_to_close {
+            destroyed_windows.push(window.hwnd as usize);
+        }
+        window.internal.current_window_state = FullWindowState::from_window_state(
+            modified,
+            window.internal.current_window_state.dropped_file.clone(),
+            window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.focused_node.clone(),
+            window.internal.current_window_state.last_hit_test.clone(),
+        );
+        if modified.size.get_layout_size() != window.internal.current_window_state.size.get_layout_size() {
+            result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+        } else if !need_scroll_render {
+            result = ProcessEventResult::ShouldReRenderCurrentWindow;
+        }
+    }
+
+    synchronize_window_state_with_os(window);
+
+    let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(
+        &window.internal.previous_window_state
+    );
+
+    if layout_callback_changed {
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    } else {
+        match callback_results.callbacks_update_screen {
+            Update::RegenerateStyledDomForCurrentWindow => {
+                return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+            },
+            Update::RegenerateStyledDomForAllWindows => {
+                return ProcessEventResult::ShouldRegenerateDomAllWindows;
+            },
+            Update::DoNothing => { },
+        }
+    }
+
+    // Re-layout and re-style the window.internal.layout_results
+    let mut style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        callback_results.css_properties_changed.as_ref(),
+        callback_results.words_changed.as_ref(),
+        &callback_results.update_focused_node,
+        azul_layout::do_the_relayout,
+    );
+
+    // FOCUS CHANGE HAPPENS HERE!
+    if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+         window.internal.current_window_state.focused_node = focus_change.new;
+    }
+
+    // Perform a system or user scroll event: only
+    // scroll nodes that were not scrolled in the current frame
+    //
+    // Update the scroll states of the nodes, returning what nodes were actually scrolled this frame
+    if let Some(scroll) = scroll {
+        // Does a system scroll and re-invokes the IFrame
+        // callbacks if scrolled out of view
+        window.do_system_scroll(scroll);
+        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+    }
+
+    if style_layout_changes.did_resize_nodes() {
+        // at least update the hit-tester
+        ProcessEventResult::UpdateHitTesterAndProcessAgain
+    } else if style_layout_changes.need_regenerate_display_list() {
+        ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+    } else if need_scroll_render || style_layout_changes.need_redraw() {
+        ProcessEventResult::ShouldReRenderCurrentWindow
+    } else {
+        result
+    }
+}
+
+#[must_use]
+fn process_timer(
+    timer_id: usize,
+    hinstance: HINSTANCE,
+    data: &mut RefAny,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::callbacks::Update;
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+    use azul_core::window_state::{StyleAndLayoutChanges, NodesToCheck};
+
+    let mut result = ProcessEventResult::DoNothing;
+
+    let callback_results = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
+        window.internal.run_single_timer(
+            timer_id,
+            frame_start,
+            data,
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    window.start_stop_timers(
+        callback_results.timers.unwrap_or_default(),
+        callback_results.timers_removed.unwrap_or_default()
+    );
+
+    window.start_stop_threads(
+        callback_results.threads.unwrap_or_default(),
+        callback_results.threads_removed.unwrap_or_default()
+    );
+
+    let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(
+        &window.internal.previous_window_state
+    );
+
+    *new_windows = callback_results.windows_created;
+
+    // see if the timers have scrolled any nodes
+    let scroll = window.internal.current_window_state
+    .process_system_scroll(&window.internal.scroll_states);
+    let need_scroll_render = scroll.is_some();
+
+    if let Some(modified) = callback_results.modified_window_state.as_ref() {
+        if modified.flags.is_about_to_close {
+            destroyed_windows.push(window.hwnd as usize);
+        }
+        window.internal.current_window_state = FullWindowState::from_window_state(
+            modified,
+            window.internal.current_window_state.dropped_file.clone(),
+            window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.focused_node.clone(),
+            window.internal.current_window_state.last_hit_test.clone(),
+        );
+        if modified.size.get_layout_size() != window.internal.current_window_state.size.get_layout_size() {
+            result = ProcessEventResult::UpdateHitTesterAndProcessAgain;
+        } else if !need_scroll_render {
+            result = ProcessEventResult::ShouldReRenderCurrentWindow;
+        }
+    }
+
+    if layout_callback_changed {
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    } else {
+        match callback_results.callbacks_update_screen {
+            Update::RegenerateStyledDomForCurrentWindow => {
+                result = ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+            },
+            Update::RegenerateStyledDomForAllWindows => {
+                result = ProcessEventResult::ShouldRegenerateDomAllWindows;
+            },
+            Update::DoNothing => { }
+        }
+    }
+
+    if let Some(scroll) = scroll {
+        window.do_system_scroll(scroll);
+        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+    }
+
+    // calculate CSS / layout changes for nodes modified by timer
+    let mut style_layout_changes = StyleAndLayoutChanges::new(
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        &mut window.internal.layout_results,
+        image_cache,
+        &mut window.internal.renderer_resources,
+        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.document_id,
+        callback_results.css_properties_changed.as_ref(),
+        callback_results.words_changed.as_ref(),
+        &callback_results.update_focused_node,
+        azul_layout::do_the_relayout,
+    );
+
+    // TODO: should a timer even be able to change the focus?
+    // FOCUS CHANGE HAPPENS HERE!
+    if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+         window.internal.current_window_state.focused_node = focus_change.new;
+    }
 
-                let windows_is_emtpy = {
-                    let mut app = &mut *app_borrow;
-                    let _ = app.windows.remove(&(hwnd as usize));
-                    app.windows.is_empty()
-                };
+    if style_layout_changes.did_resize_nodes() {
+        // at least update the hit-tester
+        ProcessEventResult::UpdateHitTesterAndProcessAgain
+    } else if style_layout_changes.need_regenerate_display_list() {
+        ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+    } else if need_scroll_render || style_layout_changes.need_redraw() {
+        ProcessEventResult::ShouldReRenderCurrentWindow
+    } else {
+        result
+    }
+}
 
-                // destruct the window data
-                let mut window_data = Box::from_raw(GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *mut SharedApplicationData);
-                mem::drop(window_data);
-                mem::drop(app_borrow);
-                if windows_is_emtpy {
-                    PostQuitMessage(0);
-                }
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            },
-            _ => {
-                mem::drop(app_borrow);
-                DefWindowProcW(hwnd, msg, wparam, lparam)
-            }
-        }
+/// Build and display the native context menu for the node under the cursor.
+///
+/// Walks the last hit test for the first hovered node that carries a context
+/// menu, builds the corresponding `HMENU` with `CreatePopupMenu` /
+/// `recursive_construct_menu`, records the id -> callback map into
+/// `window.context_menu_callbacks`, and shows it at the current cursor position
+/// with `TrackPopupMenu`. The selected command id comes back as a `WM_COMMAND`.
+/// A no-op when no node under the cursor has a context menu.
+unsafe fn show_context_menu(hwnd: HWND, window: &mut Window) {
+    use winapi::um::winuser::{
+        CreatePopupMenu, GetCursorPos, SetForegroundWindow, TrackPopupMenu,
+        ACCEL, TPM_LEFTALIGN, TPM_TOPALIGN,
+    };
+    use winapi::shared::windef::POINT;
+
+    let menu = match context_menu_under_cursor(window) {
+        Some(s) => s,
+        None => return,
     };
+
+    let mut popup = CreatePopupMenu();
+    let mut command_map = BTreeMap::new();
+    // Context menus have no accelerator table of their own; the bindings are
+    // collected but discarded.
+    let mut accels: Vec<ACCEL> = Vec::new();
+    WindowsMenuBar::recursive_construct_menu(
+        &mut popup,
+        menu.items.as_ref(),
+        &mut command_map,
+        &mut accels,
+    );
+    window.context_menu_callbacks = command_map;
+
+    let mut pos: POINT = POINT { x: 0, y: 0 };
+    GetCursorPos(&mut pos);
+    // The foreground window must own the menu or it won't dismiss on an outside
+    // click (a documented `TrackPopupMenu` requirement).
+    SetForegroundWindow(hwnd);
+    TrackPopupMenu(
+        popup,
+        TPM_TOPALIGN | TPM_LEFTALIGN,
+        pos.x,
+        pos.y,
+        0,
+        hwnd,
+        ptr::null_mut(),
+    );
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum ProcessEventResult {
-    DoNothing,
-    ShouldRegenerateDomCurrentWindow,
-    ShouldRegenerateDomAllWindows,
-    ShouldUpdateDisplayListCurrentWindow,
-    // GPU transforms changed: do another hit-test and recurse
-    // until nothing has changed anymore
-    UpdateHitTesterAndProcessAgain,
-    // Only refresh the display (in case of pure scroll or GPU-only events)
-    ShouldReRenderCurrentWindow,
+/// Find the context menu of the first hovered node in the window's last hit
+/// test, if any node under the cursor carries one.
+fn context_menu_under_cursor(window: &Window) -> Option<Menu> {
+    let hit_test = &window.internal.current_window_state.last_hit_test;
+    for (dom_id, hit) in hit_test.hovered_nodes.iter() {
+        let layout_result = window.internal.layout_results.get(dom_id.inner)?;
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        for (node_id, _) in hit.regular_hit_test_nodes.iter() {
+            if let Some(menu) = node_data.get(*node_id).and_then(|nd| nd.get_context_menu().into_option()) {
+                return Some(menu.clone());
+            }
+        }
+    }
+    None
 }
 
-// Assuming that current_window_state and the previous_window_state of the window
-// are set correctly and the hit-test has been performed, will call the callbacks
-// and return what the application should do next
+/// Run a single menu callback (menu bar or context menu) and fold its result
+/// into the same update pipeline as a hit-test event. Mirrors
+/// [`process_timer`], differing only in which `WindowInternal` entry point
+/// invokes the user callback.
 #[must_use]
-fn process_event(
+fn process_menu_command(
     hinstance: HINSTANCE,
     window: &mut Window,
+    menu_callback: &MenuCallback,
     fc_cache: &mut LazyFcCache,
     image_cache: &mut ImageCache,
     config: &AppConfig,
@@ -3164,52 +7110,24 @@ fn process_event(
     destroyed_windows: &mut Vec<usize>,
 ) -> ProcessEventResult {
 
-    use azul_core::window_state::{
-        Events, NodesToCheck, CallbacksOfHitTest,
-        StyleAndLayoutChanges,
-    };
-    use azul_core::window::FullWindowState;
     use azul_core::callbacks::Update;
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+    use azul_core::window::FullWindowState;
+    use azul_core::window_state::{StyleAndLayoutChanges, NodesToCheck};
 
-    // TODO:
-    // window.internal.current_window_state.monitor =
-    // win32_translate_monitor(MonitorFromWindow(window.hwnd, MONITOR_DEFAULTTONEAREST));
-
-    // Get events
-    let events = Events::new(
-        &window.internal.current_window_state,
-        &window.internal.previous_window_state,
-    );
-
-    // Get nodes for events
-    let nodes_to_check = NodesToCheck::new(
-        &window.internal.current_window_state.last_hit_test,
-        &events
-    );
-
-    // Invoke callbacks on nodes
-    let mut callback_results = fc_cache.apply_closure(|fc_cache| {
-
-        use azul_core::window::{RawWindowHandle, WindowsHandle};
+    let mut result = ProcessEventResult::DoNothing;
 
-        // Get callbacks for nodes
-        let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &window.internal.layout_results);
+    let callback_results = fc_cache.apply_closure(|fc_cache| {
 
         let window_handle = RawWindowHandle::Windows(WindowsHandle {
             hwnd: window.hwnd as *mut _,
             hinstance: hinstance as *mut _,
         });
-        let current_scroll_states = window.internal.get_current_scroll_states();
 
-        // Invoke user-defined callbacks in the UI
-        callbacks.call(
-            &window.internal.previous_window_state,
-            &window.internal.current_window_state,
+        window.internal.invoke_menu_callback(
+            menu_callback,
             &window_handle,
-            &current_scroll_states,
             &window.gl_context_ptr,
-            &mut window.internal.layout_results,
-            &mut window.internal.scroll_states,
             image_cache,
             fc_cache,
             &config.system_callbacks,
@@ -3229,9 +7147,8 @@ fn process_event(
         new_windows.push(w);
     }
 
-    let mut result = ProcessEventResult::DoNothing;
-
-    let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
+    let scroll = window.internal.current_window_state
+        .process_system_scroll(&window.internal.scroll_states);
     let need_scroll_render = scroll.is_some();
 
     if let Some(modified) = callback_results.modified_window_state.as_ref() {
@@ -3252,12 +7169,6 @@ fn process_event(
         }
     }
 
-    synchronize_window_state_with_os(
-        window.hwnd,
-        window.internal.previous_window_state.as_ref(),
-        &window.internal.current_window_state
-    );
-
     let layout_callback_changed = window.internal.current_window_state.layout_callback_changed(
         &window.internal.previous_window_state
     );
@@ -3276,11 +7187,18 @@ fn process_event(
         }
     }
 
-    // Re-layout and re-style the window.internal.layout_results
+    if let Some(scroll) = scroll {
+        window.do_system_scroll(scroll);
+        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+    }
+
     let mut style_layout_changes = StyleAndLayoutChanges::new(
-        &nodes_to_check,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
         &mut window.internal.layout_results,
-        &image_cache,
+        image_cache,
         &mut window.internal.renderer_resources,
         window.internal.current_window_state.size.get_layout_size(),
         &window.internal.document_id,
@@ -3290,24 +7208,11 @@ fn process_event(
         azul_layout::do_the_relayout,
     );
 
-    // FOCUS CHANGE HAPPENS HERE!
     if let Some(focus_change) = style_layout_changes.focus_change.clone() {
-         window.internal.current_window_state.focused_node = focus_change.new;
-    }
-
-    // Perform a system or user scroll event: only
-    // scroll nodes that were not scrolled in the current frame
-    //
-    // Update the scroll states of the nodes, returning what nodes were actually scrolled this frame
-    if let Some(scroll) = scroll {
-        // Does a system scroll and re-invokes the IFrame
-        // callbacks if scrolled out of view
-        window.do_system_scroll(scroll);
-        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+        window.internal.current_window_state.focused_node = focus_change.new;
     }
 
     if style_layout_changes.did_resize_nodes() {
-        // at least update the hit-tester
         ProcessEventResult::UpdateHitTesterAndProcessAgain
     } else if style_layout_changes.need_regenerate_display_list() {
         ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
@@ -3318,9 +7223,123 @@ fn process_event(
     }
 }
 
-#[must_use]
-fn process_timer(
-    timer_id: usize,
+/// Open every window requested by callbacks this frame. Each
+/// [`WindowCreateOptions`] is turned into a real `HWND` plus GL context and
+/// WebRender renderer by [`Window::create`] (which registers the window class
+/// on demand, stashes the `SharedApplicationData` in `GWLP_USERDATA` and
+/// registers the drop target), then inserted into `app.windows` keyed by HWND.
+///
+/// `shared` is the same `SharedApplicationData` the event loop runs on; a clone
+/// of its `Rc` is handed to every new window so they share the application
+/// state, image cache and GL resources.
+fn create_windows(app: &mut ApplicationData, shared: &SharedApplicationData, new: Vec<WindowCreateOptions>) {
+    let hinstance = app.hinstance;
+    for opts in new {
+        match Window::create(hinstance, opts, SharedApplicationData { inner: shared.inner.clone() }) {
+            Ok(w) => {
+                app.windows.insert(w.get_id(), w);
+            },
+            Err(e) => {
+                #[cfg(debug_assertions)]
+                eprintln!("failed to create window: {:?}", e);
+            },
+        }
+    }
+}
+
+/// Close every window a callback asked to destroy this frame. `DestroyWindow`
+/// drives the existing `WM_DESTROY` path, which drops the [`Window`] (releasing
+/// its GL context, renderer and drop target) and calls `PostQuitMessage` once
+/// the last window has gone.
+fn destroy_windows(app: &mut ApplicationData, old: Vec<usize>) {
+    use winapi::um::winuser::DestroyWindow;
+    for hwnd_key in old {
+        if let Some(window) = app.windows.get(&hwnd_key) {
+            unsafe { DestroyWindow(window.hwnd); }
+        }
+    }
+}
+
+/// Pump every window's background [`Thread`]s once, called from the 16ms
+/// `AZ_THREAD_TICK` timer. Each window with running threads is drained
+/// non-blockingly by [`process_threads`]; the resulting [`ProcessEventResult`]
+/// is mapped onto the same `AZ_REGENERATE_DOM` / `AZ_REGENERATE_DISPLAY_LIST` /
+/// `AZ_GPU_SCROLL_RENDER` messages as [`process_timer`]. Messages are collected
+/// and posted after the window iteration so the `windows` borrow is released
+/// first; windows a thread asked to close are torn down via [`destroy_windows`].
+fn run_all_threads(app: &mut ApplicationData) {
+    let hinstance = app.hinstance;
+
+    let mut posts: Vec<(HWND, u32)> = Vec::new();
+    let mut regenerate_all = false;
+    let mut destroyed = Vec::new();
+
+    {
+        let ApplicationData { data, windows, image_cache, fc_cache, config, .. } = app;
+
+        for window in windows.values_mut() {
+            if window.internal.threads.is_empty() {
+                continue;
+            }
+
+            let mut new_windows = Vec::new();
+            let mut destroyed_windows = Vec::new();
+            let ret = process_threads(
+                hinstance,
+                data,
+                window,
+                fc_cache,
+                image_cache,
+                config,
+                &mut new_windows,
+                &mut destroyed_windows,
+            );
+
+            let hwnd = window.hwnd;
+            match ret {
+                ProcessEventResult::DoNothing => { },
+                ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                    posts.push((hwnd, AZ_REGENERATE_DOM));
+                },
+                ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                    regenerate_all = true;
+                },
+                ProcessEventResult::ShouldUpdateDisplayListCurrentWindow => {
+                    posts.push((hwnd, AZ_REGENERATE_DISPLAY_LIST));
+                },
+                ProcessEventResult::UpdateHitTesterAndProcessAgain => {
+                    posts.push((hwnd, AZ_REDO_HIT_TEST));
+                },
+                ProcessEventResult::ShouldReRenderCurrentWindow => {
+                    posts.push((hwnd, AZ_GPU_SCROLL_RENDER));
+                },
+            }
+
+            destroyed.extend(destroyed_windows);
+        }
+
+        if regenerate_all {
+            for window in windows.values() {
+                posts.push((window.hwnd, AZ_REGENERATE_DOM));
+            }
+        }
+    }
+
+    for (hwnd, msg) in posts {
+        unsafe { PostMessageW(hwnd, msg, 0, 0); }
+    }
+
+    destroy_windows(app, destroyed);
+}
+
+/// Drain the background threads of a single window and fold their writeback into
+/// the update pipeline. Mirrors [`process_timer`], differing only in which
+/// `WindowInternal` entry point produces the callback results: here
+/// `run_all_threads` polls every running thread's receiver for
+/// `ThreadReceiveMsg` values, applies the returned `Update` / `RefAny`
+/// writeback and any window-state modifications, and reports which threads have
+/// finished so [`Window::start_stop_threads`] can retire them.
+fn process_threads(
     hinstance: HINSTANCE,
     data: &mut RefAny,
     window: &mut Window,
@@ -3328,7 +7347,7 @@ fn process_timer(
     image_cache: &mut ImageCache,
     config: &AppConfig,
     new_windows: &mut Vec<WindowCreateOptions>,
-    destroyed_windows: &mut Vec<usize>
+    destroyed_windows: &mut Vec<usize>,
 ) -> ProcessEventResult {
 
     use azul_core::callbacks::Update;
@@ -3344,10 +7363,7 @@ fn process_timer(
             hinstance: hinstance as *mut _,
         });
 
-        let frame_start = (config.system_callbacks.get_system_time_fn.cb)();
-        window.internal.run_single_timer(
-            timer_id,
-            frame_start,
+        window.internal.run_all_threads(
             data,
             &window_handle,
             &window.gl_context_ptr,
@@ -3373,7 +7389,7 @@ fn process_timer(
 
     *new_windows = callback_results.windows_created;
 
-    // see if the timers have scrolled any nodes
+    // see if the threads have scrolled any nodes
     let scroll = window.internal.current_window_state
     .process_system_scroll(&window.internal.scroll_states);
     let need_scroll_render = scroll.is_some();
@@ -3415,8 +7431,8 @@ fn process_timer(
         window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
     }
 
-    // calculate CSS / layout changes for nodes modified by timer
-    let mut style_layout_changes = StyleAndLayoutChanges::new(
+    // calculate CSS / layout changes for nodes modified by a thread writeback
+    let style_layout_changes = StyleAndLayoutChanges::new(
         &NodesToCheck::empty(
             window.internal.current_window_state.mouse_state.mouse_down(),
             window.internal.current_window_state.focused_node,
@@ -3432,14 +7448,11 @@ fn process_timer(
         azul_layout::do_the_relayout,
     );
 
-    // TODO: should a timer even be able to change the focus?
-    // FOCUS CHANGE HAPPENS HERE!
     if let Some(focus_change) = style_layout_changes.focus_change.clone() {
          window.internal.current_window_state.focused_node = focus_change.new;
     }
 
     if style_layout_changes.did_resize_nodes() {
-        // at least update the hit-tester
         ProcessEventResult::UpdateHitTesterAndProcessAgain
     } else if style_layout_changes.need_regenerate_display_list() {
         ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
@@ -3450,61 +7463,206 @@ fn process_timer(
     }
 }
 
-fn create_windows(app: &mut ApplicationData, new: Vec<WindowCreateOptions>) {
-    // TODO
+/// Win32 window / extended-window styles for the given decoration and
+/// resizable flags. Mirrors the style bits used in [`Window::create`].
+fn compute_window_styles(has_decorations: bool, is_resizable: bool) -> (u32, u32) {
+    use winapi::um::winuser::{
+        WS_CAPTION, WS_EX_APPWINDOW, WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_OVERLAPPED,
+        WS_POPUP, WS_SYSMENU, WS_THICKFRAME, WS_VISIBLE,
+    };
+
+    let mut style = WS_VISIBLE;
+    if has_decorations {
+        style |= WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU | WS_MINIMIZEBOX;
+        if is_resizable {
+            style |= WS_THICKFRAME | WS_MAXIMIZEBOX;
+        }
+    } else {
+        style |= WS_POPUP;
+        if is_resizable {
+            style |= WS_THICKFRAME;
+        }
+    }
+
+    (style, WS_EX_APPWINDOW)
 }
 
-fn destroy_windows(app: &mut ApplicationData, old: Vec<usize>) {
-    // TODO
+/// Save the current placement/styles and switch the window to a borderless
+/// `WS_POPUP` covering the monitor it is currently on. The saved state lets
+/// [`exit_fullscreen`] restore the exact pre-fullscreen geometry.
+unsafe fn enter_fullscreen(window: &mut Window) {
+    use winapi::um::winuser::{
+        GetMonitorInfoW, GetWindowLongPtrW, GetWindowPlacement, MonitorFromWindow,
+        SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE, GWL_STYLE, HWND_TOP,
+        MONITORINFO, MONITOR_DEFAULTTONEAREST, SWP_FRAMECHANGED, SWP_NOACTIVATE,
+        SWP_NOZORDER, WINDOWPLACEMENT, WS_POPUP, WS_VISIBLE,
+    };
+
+    let hwnd = window.hwnd;
+
+    // Only capture the pre-fullscreen state the first time; a redundant
+    // fullscreen->fullscreen transition must not clobber the saved geometry.
+    if window.pre_fullscreen.is_none() {
+        let style = GetWindowLongPtrW(hwnd, GWL_STYLE);
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        let mut placement: WINDOWPLACEMENT = mem::zeroed();
+        placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+        GetWindowPlacement(hwnd, &mut placement);
+        window.pre_fullscreen = Some(PreFullscreenState { placement, style, ex_style });
+    }
+
+    let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+    let mut mi: MONITORINFO = mem::zeroed();
+    mi.cbSize = mem::size_of::<MONITORINFO>() as u32;
+    GetMonitorInfoW(monitor, &mut mi);
+    let rc = mi.rcMonitor;
+
+    SetWindowLongPtrW(hwnd, GWL_STYLE, (WS_POPUP | WS_VISIBLE) as isize);
+    SetWindowLongPtrW(hwnd, GWL_EXSTYLE, 0);
+    SetWindowPos(
+        hwnd, HWND_TOP,
+        rc.left, rc.top, rc.right - rc.left, rc.bottom - rc.top,
+        SWP_FRAMECHANGED | SWP_NOZORDER | SWP_NOACTIVATE,
+    );
 }
 
-fn run_all_threads() {
-    // TODO
+/// Restore the placement/styles saved by [`enter_fullscreen`]. A no-op if the
+/// window was never fullscreen.
+unsafe fn exit_fullscreen(window: &mut Window) {
+    use winapi::um::winuser::{
+        SetWindowLongPtrW, SetWindowPlacement, SetWindowPos, GWL_EXSTYLE, GWL_STYLE,
+        HWND_TOP, SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    let hwnd = window.hwnd;
+    if let Some(saved) = window.pre_fullscreen.take() {
+        SetWindowLongPtrW(hwnd, GWL_STYLE, saved.style);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, saved.ex_style);
+        // Restoring the placement first, then re-applying the frame, avoids the
+        // classic bug where restoring after a maximize leaves the wrong size.
+        SetWindowPlacement(hwnd, &saved.placement);
+        SetWindowPos(
+            hwnd, HWND_TOP, 0, 0, 0, 0,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
 }
 
-// Initializes the OS window
+// Applies the initial window state to a freshly-created HWND. Unlike
+// `synchronize_window_state_with_os` there is no previous state to diff
+// against, so every relevant flag is applied once. Fullscreen bookkeeping is
+// left to the first `synchronize_window_state_with_os` call, which has the
+// owning `Window` available to save the pre-fullscreen placement.
 fn initialize_os_window(
     hwnd: HWND,
     initial_state: &WindowState,
-    internal_state: &WindowState
+    _internal_state: &WindowState,
 ) {
+    use winapi::um::winuser::{
+        SetWindowLongPtrW, SetWindowPos, SetWindowTextW, ShowWindow, GWL_EXSTYLE, GWL_STYLE,
+        HWND_NOTOPMOST, HWND_TOP, HWND_TOPMOST, SW_MAXIMIZE, SWP_FRAMECHANGED, SWP_NOACTIVATE,
+        SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    };
 
-    /*
-
-        window.set_title(new_state.title.as_str());
-        window.set_maximized(new_state.flags.is_maximized);
+    unsafe {
+        SetWindowTextW(hwnd, encode_wide(initial_state.title.as_str()).as_ptr());
+
+        let (style, ex_style) =
+            compute_window_styles(initial_state.flags.has_decorations, initial_state.flags.is_resizable);
+        SetWindowLongPtrW(hwnd, GWL_STYLE, style as isize);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style as isize);
+        SetWindowPos(
+            hwnd, HWND_TOP, 0, 0, 0, 0,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
 
-        if new_state.flags.is_fullscreen {
-            window.set_fullscreen(Some(Fullscreen::Borderless(window.current_monitor())));
+        if initial_state.flags.is_always_on_top {
+            SetWindowPos(hwnd, HWND_TOPMOST, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
         } else {
-            window.set_fullscreen(None);
+            SetWindowPos(hwnd, HWND_NOTOPMOST, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+        }
+
+        if initial_state.flags.is_maximized {
+            ShowWindow(hwnd, SW_MAXIMIZE);
         }
+    }
+}
+
+// Diff the window's current state against its previous state and issue only the
+// Win32 calls for the flags that actually changed, so unrelated state edits
+// don't churn the window (the "restore after maximize leaves the wrong size"
+// family of bugs). Fullscreen is handled specially: it saves and later restores
+// the pre-fullscreen placement + styles via [`enter_fullscreen`] /
+// [`exit_fullscreen`].
+fn synchronize_window_state_with_os(window: &mut Window) {
+    use winapi::um::winuser::{
+        SetWindowLongPtrW, SetWindowPos, SetWindowTextW, ShowWindow, GWL_EXSTYLE, GWL_STYLE,
+        HWND_NOTOPMOST, HWND_TOP, HWND_TOPMOST, SW_MAXIMIZE, SW_RESTORE, SWP_FRAMECHANGED,
+        SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    let hwnd = window.hwnd;
+
+    // Snapshot the primitives we need up front so the window can be mutated
+    // (fullscreen bookkeeping) without holding a borrow on its state.
+    let (cur, prev) = (
+        &window.internal.current_window_state,
+        window.internal.previous_window_state.as_ref(),
+    );
+
+    let title = cur.title.clone();
+    let title_changed = prev.map_or(true, |p| p.title != cur.title);
+
+    let is_maximized = cur.flags.is_maximized;
+    let maximized_changed = prev.map_or(true, |p| p.flags.is_maximized != is_maximized);
+
+    let is_fullscreen = cur.flags.is_fullscreen;
+    let fullscreen_changed = prev.map_or(true, |p| p.flags.is_fullscreen != is_fullscreen);
+
+    let has_decorations = cur.flags.has_decorations;
+    let is_resizable = cur.flags.is_resizable;
+    let style_changed = prev.map_or(true, |p| {
+        p.flags.has_decorations != has_decorations || p.flags.is_resizable != is_resizable
+    });
 
-        window.set_decorations(new_state.flags.has_decorations);
-        window.set_inner_size(translate_logical_size(new_state.size.dimensions));
-        window.set_min_inner_size(new_state.size.min_dimensions.into_option().map(translate_logical_size));
-        window.set_min_inner_size(new_state.size.max_dimensions.into_option().map(translate_logical_size));
+    let is_always_on_top = cur.flags.is_always_on_top;
+    let topmost_changed = prev.map_or(true, |p| p.flags.is_always_on_top != is_always_on_top);
 
-        if let WindowPosition::Initialized(new_position) = new_state.position {
-            let new_position: PhysicalPosition<i32> = new_position.into();
-            window.set_outer_position(translate_logical_position(new_position.to_logical(new_state.size.hidpi_factor)));
+    unsafe {
+        if title_changed {
+            SetWindowTextW(hwnd, encode_wide(title.as_str()).as_ptr());
         }
 
-        if let ImePosition::Initialized(new_ime_position) = new_state.ime_position {
-            window.set_ime_position(translate_logical_position(new_ime_position));
+        // Fullscreen owns the window styles while active, so handle it first and
+        // skip the decoration/resizable and maximize diffs while it is on.
+        if fullscreen_changed {
+            if is_fullscreen {
+                enter_fullscreen(window);
+            } else {
+                exit_fullscreen(window);
+            }
+        } else if style_changed && !is_fullscreen {
+            let (style, ex_style) = compute_window_styles(has_decorations, is_resizable);
+            SetWindowLongPtrW(hwnd, GWL_STYLE, style as isize);
+            SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style as isize);
+            SetWindowPos(
+                hwnd, HWND_TOP, 0, 0, 0, 0,
+                SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+            );
         }
 
-        window.set_always_on_top(new_state.flags.is_always_on_top);
-        window.set_resizable(new_state.flags.is_resizable);
-    */
-}
+        if maximized_changed && !is_fullscreen {
+            ShowWindow(hwnd, if is_maximized { SW_MAXIMIZE } else { SW_RESTORE });
+        }
 
-fn synchronize_window_state_with_os(
-    window: HWND,
-    previous_state: Option<&FullWindowState>,
-    current_state: &FullWindowState
-) {
-    // TODO: window.set_title
+        if topmost_changed {
+            let insert_after = if is_always_on_top { HWND_TOPMOST } else { HWND_NOTOPMOST };
+            SetWindowPos(
+                hwnd, insert_after, 0, 0, 0, 0,
+                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+            );
+        }
+    }
 }
 
 fn send_resource_updates(
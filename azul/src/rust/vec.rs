@@ -1,9 +1,10 @@
-    #![allow(dead_code, unused_imports)]
-    //! Definition of azuls internal `Vec<*>` wrappers
-    use crate::dll::*;
-    use core::ffi::c_void;
+    #![allow(dead_code, unused_imports)]
+    //! Definition of azuls internal `Vec<*>` wrappers
+    use crate::dll::*;
+    use core::ffi::c_void;
     use core::iter;
     use core::fmt;
+    use core::ptr;
 
     use alloc::vec::{self, Vec};
     use alloc::slice;
@@ -14,6 +15,24 @@
         GLuint as AzGLuint,
     };
 
+    // Out of scope here: an inline-storage ("small vector") optimization for
+    // the short style vectors — `CssDeclarationVec`, `CssPathSelectorVec`,
+    // `CssRuleBlockVec`, `LinearColorStopVec`, `StyledNodeVec` — needs the
+    // `$struct_name` wrapper itself to become a tagged union of an inline
+    // `{ data: [T; N], len }` and a spilled `{ ptr, len, cap }`. That is a
+    // different thing from growing the existing spilled buffer in place,
+    // which `grow_to` below does do, using only the `ptr`/`len`/`cap` fields
+    // this file already reads and writes freely: inline storage instead needs
+    // a fourth, discriminant field so the wrapper can represent "inline" at
+    // all, and that field has to be part of the `#[repr(C)]` layout every
+    // `az_*_vec_*` C entry point agrees on. Those structs are defined by
+    // `crate::dll`, which is generated output (no source for it exists in
+    // this crate, only the C entry points it emits), so adding a field to
+    // them — unlike reallocating the buffer they already own — is a
+    // prerequisite change in the API code generator, not something
+    // expressible from this file. Once that tag exists, `impl_vec!` below
+    // would need `as_ref`/`as_mut`/`Drop` (and `grow_to`) taught to check it;
+    // until then every mutator here operates on the spilled buffer alone.
     macro_rules! impl_vec {($struct_type:ident, $struct_name:ident) => (
 
         impl $struct_name {
@@ -49,11 +68,28 @@
                 self.len
             }
 
+            /// Spare capacity of the buffer. Genuinely reflects unused
+            /// allocation: `push`/`insert`/`reserve`/etc. grow the buffer in
+            /// place (see [`grow_to`](Self::grow_to)) rather than rebuilding it
+            /// through a `Vec<T>` that would collapse `cap` back to `len` on
+            /// every call.
             #[inline]
             pub fn capacity(&self) -> usize {
                 self.cap
             }
 
+            /// Force the length of the vector to `new_len`.
+            ///
+            /// # Safety
+            ///
+            /// `new_len` must be `<= capacity()` and every element in
+            /// `0..new_len` must be initialized. Used by the iterator adaptors
+            /// that write directly into the buffer.
+            #[inline]
+            pub unsafe fn set_len(&mut self, new_len: usize) {
+                self.len = new_len;
+            }
+
             #[inline]
             pub fn is_empty(&self) -> bool {
                 self.len == 0
@@ -63,10 +99,387 @@
                 self.as_ref().get(index)
             }
 
+            #[inline]
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut $struct_type> {
+                self.as_mut().get_mut(index)
+            }
+
             #[inline]
             pub unsafe fn get_unchecked(&self, index: usize) -> &$struct_type {
                 self.as_ref().get_unchecked(index)
             }
+
+            /// Borrow the FFI-owned buffer as a slice.
+            #[inline]
+            pub fn as_slice(&self) -> &[$struct_type] {
+                self.as_ref()
+            }
+
+            /// Mutably borrow the FFI-owned buffer as a slice.
+            #[inline]
+            pub fn as_mut_slice(&mut self) -> &mut [$struct_type] {
+                self.as_mut()
+            }
+
+            /// Append a single element to the end of the vector, growing the
+            /// buffer in place (via [`grow_to`](Self::grow_to)) if it's full.
+            pub fn push(&mut self, value: $struct_type) {
+                if self.len == self.cap {
+                    self.grow_to(self.len + 1);
+                }
+                unsafe { ptr::write(self.ptr.add(self.len), value) };
+                self.len += 1;
+            }
+
+            /// Remove and return the last element, or `None` if empty.
+            pub fn pop(&mut self) -> Option<$struct_type> {
+                if self.len == 0 {
+                    None
+                } else {
+                    self.len -= 1;
+                    Some(unsafe { ptr::read(self.ptr.add(self.len)) })
+                }
+            }
+
+            /// Insert an element at `index`, shifting everything after it to the
+            /// right. Panics if `index > len`, matching `Vec::insert`.
+            pub fn insert(&mut self, index: usize, value: $struct_type) {
+                assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+                if self.len == self.cap {
+                    self.grow_to(self.len + 1);
+                }
+                unsafe {
+                    let p = self.ptr.add(index);
+                    if index < self.len {
+                        ptr::copy(p, p.add(1), self.len - index);
+                    }
+                    ptr::write(p, value);
+                }
+                self.len += 1;
+            }
+
+            /// Remove and return the element at `index`, shifting everything
+            /// after it to the left. Panics if `index >= len`.
+            pub fn remove(&mut self, index: usize) -> $struct_type {
+                assert!(index < self.len, "removal index (is {}) should be < len (is {})", index, self.len);
+                unsafe {
+                    let p = self.ptr.add(index);
+                    let result = ptr::read(p);
+                    ptr::copy(p.add(1), p, self.len - index - 1);
+                    self.len -= 1;
+                    result
+                }
+            }
+
+            /// Remove the element at `index` and return it, swapping in the last
+            /// element to fill the gap (O(1), does not preserve order). Panics if
+            /// `index >= len`.
+            pub fn swap_remove(&mut self, index: usize) -> $struct_type {
+                assert!(index < self.len, "swap_remove index (is {}) should be < len (is {})", index, self.len);
+                unsafe {
+                    let last = self.len - 1;
+                    let p = self.ptr.add(index);
+                    let result = ptr::read(p);
+                    if index != last {
+                        ptr::copy(self.ptr.add(last), p, 1);
+                    }
+                    self.len = last;
+                    result
+                }
+            }
+
+            /// Shorten the vector to `len` elements, dropping the rest in place.
+            /// A no-op if `len >= self.len()`.
+            pub fn truncate(&mut self, len: usize) {
+                if len >= self.len {
+                    return;
+                }
+                unsafe {
+                    let remaining = self.len - len;
+                    let tail = ptr::slice_from_raw_parts_mut(self.ptr.add(len), remaining);
+                    self.len = len;
+                    ptr::drop_in_place(tail);
+                }
+            }
+
+            /// Remove every element, leaving an empty vector.
+            pub fn clear(&mut self) {
+                self.truncate(0);
+            }
+
+            /// Append a copy of every element in `other` to the end of the
+            /// vector, reserving once up front and cloning straight into the
+            /// buffer.
+            pub fn extend_from_slice(&mut self, other: &[$struct_type]) where $struct_type: Clone {
+                self.reserve(other.len());
+                unsafe {
+                    for (i, item) in other.iter().enumerate() {
+                        ptr::write(self.ptr.add(self.len + i), item.clone());
+                    }
+                }
+                self.len += other.len();
+            }
+
+            /// Move every element out of `other` and onto the end of `self`,
+            /// leaving `other` empty. The source keeps its allocation for reuse.
+            pub fn append(&mut self, other: &mut Self) {
+                self.reserve(other.len);
+                unsafe {
+                    ptr::copy_nonoverlapping(other.ptr, self.ptr.add(self.len), other.len);
+                }
+                self.len += other.len;
+                other.len = 0;
+            }
+
+            /// Grows the buffer so `capacity()` is at least `min_cap`, copying
+            /// the existing elements into a freshly `with_capacity`-allocated
+            /// buffer and swapping it in. Doubles from the current capacity
+            /// (floor of 4) so repeated callers amortize to O(1), the same
+            /// growth factor `std::vec::Vec` uses. A no-op if `min_cap` is
+            /// already covered.
+            fn grow_to(&mut self, min_cap: usize) {
+                if min_cap <= self.cap {
+                    return;
+                }
+                let new_cap = if self.cap == 0 {
+                    min_cap.max(4)
+                } else {
+                    self.cap.saturating_mul(2).max(min_cap)
+                };
+                let mut grown = Self::with_capacity(new_cap);
+                unsafe {
+                    ptr::copy_nonoverlapping(self.ptr, grown.ptr, self.len);
+                    grown.len = self.len;
+                    // The elements now live in `grown`; disown them here so
+                    // dropping the old buffer below doesn't double-drop them.
+                    self.len = 0;
+                }
+                *self = grown;
+            }
+
+            /// Reserve room for at least `additional` more elements, growing the
+            /// C-owned buffer itself geometrically (via [`grow_to`](Self::grow_to))
+            /// so a bulk insert reallocates at most once. `additional` is counted
+            /// in usable elements, not allocator-rounded bytes.
+            pub fn reserve(&mut self, additional: usize) {
+                self.grow_to(self.len.saturating_add(additional));
+            }
+
+            /// Reserve room for exactly `additional` more elements without the
+            /// geometric slack `reserve` leaves, for a final known-size fill.
+            pub fn reserve_exact(&mut self, additional: usize) {
+                let required = self.len.saturating_add(additional);
+                if required <= self.cap {
+                    return;
+                }
+                let mut grown = Self::with_capacity(required);
+                unsafe {
+                    ptr::copy_nonoverlapping(self.ptr, grown.ptr, self.len);
+                    grown.len = self.len;
+                    self.len = 0;
+                }
+                *self = grown;
+            }
+
+            /// Drop any spare capacity so `capacity()` equals `len()`.
+            pub fn shrink_to_fit(&mut self) {
+                if self.cap == self.len {
+                    return;
+                }
+                let mut shrunk = Self::with_capacity(self.len);
+                unsafe {
+                    ptr::copy_nonoverlapping(self.ptr, shrunk.ptr, self.len);
+                    shrunk.len = self.len;
+                    self.len = 0;
+                }
+                *self = shrunk;
+            }
+
+            /// Keep only the elements for which `f` returns `true`, removing the
+            /// rest in place without allocating an intermediate `Vec`.
+            ///
+            /// Panic-safe: `len` is zeroed up front and only restored to the
+            /// number of survivors at the end, so a panicking predicate can
+            /// leak (but never double-drop) the remaining elements.
+            pub fn retain<F: FnMut(&$struct_type) -> bool>(&mut self, mut f: F) {
+                let len = self.len;
+                let ptr = self.ptr;
+                self.len = 0;
+                unsafe {
+                    let mut w = 0usize;
+                    let mut r = 0usize;
+                    while r < len {
+                        let elem = ptr.add(r);
+                        if f(&*elem) {
+                            if r != w {
+                                ptr::copy(elem, ptr.add(w), 1);
+                            }
+                            w += 1;
+                        } else {
+                            ptr::drop_in_place(elem);
+                        }
+                        r += 1;
+                    }
+                    self.len = w;
+                }
+            }
+
+            /// Remove and yield the elements for which `f` returns `true`,
+            /// back-shifting the survivors to close the gap. The returned
+            /// iterator's `Drop` finishes shifting any tail that was not
+            /// iterated, so the buffer is never left with a hole.
+            /// `Vec::drain_filter`-style alias for [`extract_if`](Self::extract_if):
+            /// yields and removes the elements matching `f`, back-shifting the
+            /// survivors in a single pass.
+            pub fn drain_filter<F: FnMut(&mut $struct_type) -> bool>(&mut self, f: F) -> ExtractIf<'_, $struct_type, F> {
+                self.extract_if(f)
+            }
+
+            pub fn extract_if<F: FnMut(&mut $struct_type) -> bool>(&mut self, f: F) -> ExtractIf<'_, $struct_type, F> {
+                let orig_len = self.len;
+                let ptr = self.ptr;
+                // Disown the buffer contents for the lifetime of the iterator so
+                // a leaked `ExtractIf` can't expose moved-out slots.
+                self.len = 0;
+                ExtractIf {
+                    len: &mut self.len,
+                    ptr,
+                    f,
+                    orig_len,
+                    read: 0,
+                    write: 0,
+                }
+            }
+
+            /// Remove the elements in `range` and yield them by value, leaving
+            /// the tail in place. The wrapper's `len` is clamped to the range
+            /// start for the lifetime of the iterator so a leaked `Drain` can
+            /// never expose the removed slots; the tail is moved down to close
+            /// the gap when the iterator is dropped.
+            ///
+            /// Panics if the range is out of bounds, matching `Vec::drain`.
+            pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, $struct_type> {
+                use core::ops::Bound;
+
+                let len = self.len;
+                let start = match range.start_bound() {
+                    Bound::Included(&n) => n,
+                    Bound::Excluded(&n) => n + 1,
+                    Bound::Unbounded => 0,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(&n) => n + 1,
+                    Bound::Excluded(&n) => n,
+                    Bound::Unbounded => len,
+                };
+                assert!(start <= end, "drain start is greater than end");
+                assert!(end <= len, "drain end is out of bounds");
+
+                let ptr = self.ptr;
+                self.len = start;
+                Drain {
+                    len: &mut self.len,
+                    ptr,
+                    start,
+                    end,
+                    idx: start,
+                    orig_len: len,
+                }
+            }
+
+            /// Run `f` over every element in place, stopping and returning the
+            /// first `Err` it produces. Elements before the failure keep their
+            /// mutations; the vector itself is never reallocated.
+            pub fn try_for_each_mut<E, F: FnMut(&mut $struct_type) -> Result<(), E>>(&mut self, mut f: F) -> Result<(), E> {
+                for elem in self.as_mut() {
+                    f(elem)?;
+                }
+                Ok(())
+            }
+
+            /// Replace every element by moving it out, applying `f`, and writing
+            /// the result back into the same slot — no reallocation and `len` /
+            /// `cap` are unchanged.
+            ///
+            /// If `f` panics, the slot it was working on is left empty and `len`
+            /// is shrunk past it, so the partially-transformed element is not
+            /// double-dropped (the untouched tail leaks instead).
+            pub fn map_in_place<F: FnMut($struct_type) -> $struct_type>(&mut self, mut f: F) {
+                let ptr = self.ptr;
+                let len = self.len;
+                for i in 0..len {
+                    unsafe {
+                        let slot = ptr.add(i);
+                        let old = ptr::read(slot);
+                        // Slot `i` is momentarily uninitialized.
+                        self.len = i;
+                        let new = f(old);
+                        ptr::write(slot, new);
+                        self.len = i + 1;
+                    }
+                }
+                self.len = len;
+            }
+
+            /// Remove consecutive elements that map to the same key, keeping the
+            /// first of each run. Works in place via `ptr::drop_in_place` and a
+            /// back-shifting write pointer; `len` is fixed up progressively so a
+            /// panicking `key` closure cannot leave survivors double-dropped.
+            pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut $struct_type) -> K>(&mut self, mut key: F) {
+                let len = self.len;
+                if len <= 1 {
+                    return;
+                }
+                let ptr = self.ptr;
+                unsafe {
+                    let mut prev_key = key(&mut *ptr.add(0));
+                    let mut w = 1usize;
+                    self.len = 1;
+                    for r in 1..len {
+                        let elem = ptr.add(r);
+                        let k = key(&mut *elem);
+                        if k == prev_key {
+                            ptr::drop_in_place(elem);
+                        } else {
+                            if r != w {
+                                ptr::copy(elem, ptr.add(w), 1);
+                            }
+                            prev_key = k;
+                            w += 1;
+                            self.len = w;
+                        }
+                    }
+                    self.len = w;
+                }
+            }
+
+            /// Remove consecutive duplicate elements, keeping the first of each
+            /// run. Shortcut for [`dedup_by_key`](Self::dedup_by_key) when the
+            /// element type is itself comparable.
+            pub fn dedup(&mut self) where $struct_type: PartialEq {
+                let len = self.len;
+                if len <= 1 {
+                    return;
+                }
+                let ptr = self.ptr;
+                unsafe {
+                    let mut w = 1usize;
+                    self.len = 1;
+                    for r in 1..len {
+                        let elem = ptr.add(r);
+                        if *elem == *ptr.add(w - 1) {
+                            ptr::drop_in_place(elem);
+                        } else {
+                            if r != w {
+                                ptr::copy(elem, ptr.add(w), 1);
+                            }
+                            w += 1;
+                            self.len = w;
+                        }
+                    }
+                    self.len = w;
+                }
+            }
         }
 
         impl Default for $struct_name {
@@ -87,10 +500,81 @@
             }
         }
 
+        impl core::ops::Deref for $struct_name {
+            type Target = [$struct_type];
+            fn deref(&self) -> &[$struct_type] {
+                self.as_ref()
+            }
+        }
+
+        impl core::ops::DerefMut for $struct_name {
+            fn deref_mut(&mut self) -> &mut [$struct_type] {
+                self.as_mut()
+            }
+        }
+
+        impl<I: core::slice::SliceIndex<[$struct_type]>> core::ops::Index<I> for $struct_name {
+            type Output = I::Output;
+            fn index(&self, index: I) -> &Self::Output {
+                core::ops::Index::index(self.as_ref(), index)
+            }
+        }
+
+        impl<I: core::slice::SliceIndex<[$struct_type]>> core::ops::IndexMut<I> for $struct_name {
+            fn index_mut(&mut self, index: I) -> &mut Self::Output {
+                core::ops::IndexMut::index_mut(self.as_mut(), index)
+            }
+        }
+
+        impl IntoIterator for $struct_name {
+            type Item = $struct_type;
+            type IntoIter = vec::IntoIter<$struct_type>;
+            fn into_iter(self) -> Self::IntoIter {
+                let v: Vec<$struct_type> = self.into();
+                v.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $struct_name {
+            type Item = &'a $struct_type;
+            type IntoIter = slice::Iter<'a, $struct_type>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.as_ref().iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $struct_name {
+            type Item = &'a mut $struct_type;
+            type IntoIter = slice::IterMut<'a, $struct_type>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.as_mut().iter_mut()
+            }
+        }
+
         impl iter::FromIterator<$struct_type> for $struct_name {
             fn from_iter<T>(iter: T) -> Self where T: IntoIterator<Item = $struct_type> {
-                let v: Vec<$struct_type> = Vec::from_iter(iter);
-                v.into()
+                let iter = iter.into_iter();
+                // Pre-reserve from the iterator's lower bound so the common case
+                // (an exact-size iterator) fills a single allocation instead of
+                // growing geometrically from zero; `push` writes straight into
+                // that buffer, so there is no intermediate `Vec<T>`.
+                let (lower, _) = iter.size_hint();
+                let mut v = Self::with_capacity(lower);
+                for item in iter {
+                    v.push(item);
+                }
+                v
+            }
+        }
+
+        impl Extend<$struct_type> for $struct_name {
+            fn extend<T: IntoIterator<Item = $struct_type>>(&mut self, iter: T) {
+                let iter = iter.into_iter();
+                let (lower, _) = iter.size_hint();
+                self.reserve(lower);
+                for item in iter {
+                    self.push(item);
+                }
             }
         }
 
@@ -116,6 +600,116 @@
         // Drop, Debug + Clone already implemented by default
     )}
 
+    /// Draining filter iterator returned by `extract_if`. Yields the elements
+    /// for which the predicate returned `true` and back-shifts the survivors;
+    /// the wrapper's `len` is restored when the iterator is dropped.
+    pub struct ExtractIf<'a, T: 'a, F: FnMut(&mut T) -> bool> {
+        /// Pointer to the wrapper's `len`, held at `0` while draining.
+        len: &'a mut usize,
+        ptr: *mut T,
+        f: F,
+        orig_len: usize,
+        /// Next index to examine.
+        read: usize,
+        /// Next slot a survivor is written to.
+        write: usize,
+    }
+
+    impl<'a, T: 'a, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {
+            unsafe {
+                while self.read < self.orig_len {
+                    let elem = self.ptr.add(self.read);
+                    let remove = (self.f)(&mut *elem);
+                    self.read += 1;
+                    if remove {
+                        return Some(ptr::read(elem));
+                    } else {
+                        if self.write != self.read - 1 {
+                            ptr::copy(elem, self.ptr.add(self.write), 1);
+                        }
+                        self.write += 1;
+                    }
+                }
+                None
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, Some(self.orig_len - self.read))
+        }
+    }
+
+    /// By-value draining iterator for a sub-range, returned by `drain`.
+    pub struct Drain<'a, T: 'a> {
+        /// Pointer to the wrapper's `len`, held at `start` while draining.
+        len: &'a mut usize,
+        ptr: *mut T,
+        /// First drained index (where the tail is memmoved to on drop).
+        start: usize,
+        /// One past the last drained index.
+        end: usize,
+        /// Next drained index to yield.
+        idx: usize,
+        /// Length of the vector before the drain began.
+        orig_len: usize,
+    }
+
+    impl<'a, T: 'a> Iterator for Drain<'a, T> {
+        type Item = T;
+        fn next(&mut self) -> Option<T> {
+            if self.idx < self.end {
+                let elem = unsafe { ptr::read(self.ptr.add(self.idx)) };
+                self.idx += 1;
+                Some(elem)
+            } else {
+                None
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.end - self.idx;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<'a, T: 'a> ExactSizeIterator for Drain<'a, T> {}
+
+    impl<'a, T: 'a> Drop for Drain<'a, T> {
+        fn drop(&mut self) {
+            unsafe {
+                // Drop any removed elements the caller never took.
+                while self.idx < self.end {
+                    ptr::drop_in_place(self.ptr.add(self.idx));
+                    self.idx += 1;
+                }
+                // Move the untouched tail down to close the gap.
+                let tail_len = self.orig_len - self.end;
+                if tail_len > 0 {
+                    ptr::copy(self.ptr.add(self.end), self.ptr.add(self.start), tail_len);
+                }
+                *self.len = self.start + tail_len;
+            }
+        }
+    }
+
+    impl<'a, T: 'a, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'a, T, F> {
+        fn drop(&mut self) {
+            unsafe {
+                // Shift any elements we never looked at down so the buffer stays
+                // contiguous, then publish the final length.
+                while self.read < self.orig_len {
+                    let elem = self.ptr.add(self.read);
+                    if self.write != self.read {
+                        ptr::copy(elem, self.ptr.add(self.write), 1);
+                    }
+                    self.read += 1;
+                    self.write += 1;
+                }
+                *self.len = self.write;
+            }
+        }
+    }
+
     impl_vec!(u8,  AzU8Vec);
     impl_vec!(u32, AzU32Vec);
     impl_vec!(u32, AzScanCodeVec);
@@ -169,619 +763,726 @@
 
             // delete() not necessary because StringVec is stack-allocated
         }
-    }    use crate::dom::{CallbackData, Dom, IdOrClass, NodeData, NodeDataInlineCssProperty};
-    use crate::css::{CssDeclaration, CssPathSelector, CssProperty, CssRuleBlock, LinearColorStop, RadialColorStop, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundRepeat, StyleBackgroundSize, StyleTransform, Stylesheet};
-    use crate::svg::{SvgMultiPolygon, SvgPath, SvgPathElement, SvgVertex};
-    use crate::gl::{DebugMessage, VertexAttribute};
-    use crate::window::{StringPair, VirtualKeyCode, XWindowType};
-    use crate::style::{CascadeInfo, Node, ParentWithNodeDepth, StyledNode, TagIdToNodeIdMapping};
-    use crate::str::String;
-    use crate::callbacks::NodeId;
-
-
-    /// Wrapper over a Rust-allocated `Vec<Dom>`
-    #[doc(inline)] pub use crate::dll::AzDomVec as DomVec;
-
-    impl DomVec {
-        /// Creates a new, empty Rust `Vec<Dom>`
-        pub fn new() -> Self { unsafe { crate::dll::az_dom_vec_new() } }
-        /// Creates a new, empty Rust `Vec<Dom>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_dom_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<Dom>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzDom, len: usize) -> Self { unsafe { crate::dll::az_dom_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for DomVec { fn clone(&self) -> Self { unsafe { crate::dll::az_dom_vec_deep_copy(self) } } }
-    impl Drop for DomVec { fn drop(&mut self) { unsafe { crate::dll::az_dom_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<IdOrClass>`
-    #[doc(inline)] pub use crate::dll::AzIdOrClassVec as IdOrClassVec;
-
-    impl IdOrClassVec {
-        /// Creates a new, empty Rust `Vec<IdOrClass>`
-        pub fn new() -> Self { unsafe { crate::dll::az_id_or_class_vec_new() } }
-        /// Creates a new, empty Rust `Vec<IdOrClass>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_id_or_class_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<IdOrClass>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzIdOrClass, len: usize) -> Self { unsafe { crate::dll::az_id_or_class_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for IdOrClassVec { fn clone(&self) -> Self { unsafe { crate::dll::az_id_or_class_vec_deep_copy(self) } } }
-    impl Drop for IdOrClassVec { fn drop(&mut self) { unsafe { crate::dll::az_id_or_class_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
-    #[doc(inline)] pub use crate::dll::AzNodeDataInlineCssPropertyVec as NodeDataInlineCssPropertyVec;
-
-    impl NodeDataInlineCssPropertyVec {
-        /// Creates a new, empty Rust `Vec<NodeDataInlineCssProperty>`
-        pub fn new() -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_new() } }
-        /// Creates a new, empty Rust `Vec<NodeDataInlineCssProperty>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<NodeDataInlineCssProperty>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzNodeDataInlineCssProperty, len: usize) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for NodeDataInlineCssPropertyVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_deep_copy(self) } } }
-    impl Drop for NodeDataInlineCssPropertyVec { fn drop(&mut self) { unsafe { crate::dll::az_node_data_inline_css_property_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundContent>`
-    #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVec as StyleBackgroundContentVec;
-
-    impl StyleBackgroundContentVec {
-        /// Creates a new, empty Rust `Vec<StyleBackgroundContent>`
-        pub fn new() -> Self { unsafe { crate::dll::az_style_background_content_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyleBackgroundContent>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_content_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyleBackgroundContent>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyleBackgroundContent, len: usize) -> Self { unsafe { crate::dll::az_style_background_content_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyleBackgroundContentVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_content_vec_deep_copy(self) } } }
-    impl Drop for StyleBackgroundContentVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_content_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundPosition>`
-    #[doc(inline)] pub use crate::dll::AzStyleBackgroundPositionVec as StyleBackgroundPositionVec;
-
-    impl StyleBackgroundPositionVec {
-        /// Creates a new, empty Rust `Vec<StyleBackgroundPosition>`
-        pub fn new() -> Self { unsafe { crate::dll::az_style_background_position_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyleBackgroundPosition>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_position_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyleBackgroundPosition>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyleBackgroundPosition, len: usize) -> Self { unsafe { crate::dll::az_style_background_position_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyleBackgroundPositionVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_position_vec_deep_copy(self) } } }
-    impl Drop for StyleBackgroundPositionVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_position_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundRepeat>`
-    #[doc(inline)] pub use crate::dll::AzStyleBackgroundRepeatVec as StyleBackgroundRepeatVec;
-
-    impl StyleBackgroundRepeatVec {
-        /// Creates a new, empty Rust `Vec<StyleBackgroundRepeat>`
-        pub fn new() -> Self { unsafe { crate::dll::az_style_background_repeat_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyleBackgroundRepeat>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyleBackgroundRepeat>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyleBackgroundRepeat, len: usize) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyleBackgroundRepeatVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_deep_copy(self) } } }
-    impl Drop for StyleBackgroundRepeatVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_repeat_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundSize>`
-    #[doc(inline)] pub use crate::dll::AzStyleBackgroundSizeVec as StyleBackgroundSizeVec;
-
-    impl StyleBackgroundSizeVec {
-        /// Creates a new, empty Rust `Vec<StyleBackgroundSize>`
-        pub fn new() -> Self { unsafe { crate::dll::az_style_background_size_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyleBackgroundSize>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_size_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyleBackgroundSize>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyleBackgroundSize, len: usize) -> Self { unsafe { crate::dll::az_style_background_size_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyleBackgroundSizeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_size_vec_deep_copy(self) } } }
-    impl Drop for StyleBackgroundSizeVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_size_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
-    #[doc(inline)] pub use crate::dll::AzStyleTransformVec as StyleTransformVec;
-
-    impl StyleTransformVec {
-        /// Creates a new, empty Rust `Vec<StyleTransform>`
-        pub fn new() -> Self { unsafe { crate::dll::az_style_transform_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyleTransform>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_transform_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyleTransform>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyleTransform, len: usize) -> Self { unsafe { crate::dll::az_style_transform_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyleTransformVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_transform_vec_deep_copy(self) } } }
-    impl Drop for StyleTransformVec { fn drop(&mut self) { unsafe { crate::dll::az_style_transform_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<CssProperty>`
-    #[doc(inline)] pub use crate::dll::AzCssPropertyVec as CssPropertyVec;
-
-    impl CssPropertyVec {
-        /// Creates a new, empty Rust `Vec<CssProperty>`
-        pub fn new() -> Self { unsafe { crate::dll::az_css_property_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CssProperty>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_property_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CssProperty>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCssProperty, len: usize) -> Self { unsafe { crate::dll::az_css_property_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CssPropertyVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_property_vec_deep_copy(self) } } }
-    impl Drop for CssPropertyVec { fn drop(&mut self) { unsafe { crate::dll::az_css_property_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<SvgMultiPolygon>`
-    #[doc(inline)] pub use crate::dll::AzSvgMultiPolygonVec as SvgMultiPolygonVec;
-
-    impl SvgMultiPolygonVec {
-        /// Creates a new, empty Rust `Vec<SvgMultiPolygon>`
-        pub fn new() -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_new() } }
-        /// Creates a new, empty Rust `Vec<SvgMultiPolygon>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<SvgMultiPolygon>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzSvgMultiPolygon, len: usize) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for SvgMultiPolygonVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_deep_copy(self) } } }
-    impl Drop for SvgMultiPolygonVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_multi_polygon_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<SvgPath>`
-    #[doc(inline)] pub use crate::dll::AzSvgPathVec as SvgPathVec;
-
-    impl SvgPathVec {
-        /// Creates a new, empty Rust `Vec<SvgPath>`
-        pub fn new() -> Self { unsafe { crate::dll::az_svg_path_vec_new() } }
-        /// Creates a new, empty Rust `Vec<SvgPath>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_path_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<SvgPath>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzSvgPath, len: usize) -> Self { unsafe { crate::dll::az_svg_path_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for SvgPathVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_path_vec_deep_copy(self) } } }
-    impl Drop for SvgPathVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_path_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<VertexAttribute>`
-    #[doc(inline)] pub use crate::dll::AzVertexAttributeVec as VertexAttributeVec;
-
-    impl VertexAttributeVec {
-        /// Creates a new, empty Rust `Vec<VertexAttribute>`
-        pub fn new() -> Self { unsafe { crate::dll::az_vertex_attribute_vec_new() } }
-        /// Creates a new, empty Rust `Vec<VertexAttribute>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<VertexAttribute>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzVertexAttribute, len: usize) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for VertexAttributeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_deep_copy(self) } } }
-    impl Drop for VertexAttributeVec { fn drop(&mut self) { unsafe { crate::dll::az_vertex_attribute_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `VertexAttribute`
-    #[doc(inline)] pub use crate::dll::AzSvgPathElementVec as SvgPathElementVec;
-
-    impl SvgPathElementVec {
-        /// Creates a new, empty Rust `Vec<SvgPathElement>`
-        pub fn new() -> Self { unsafe { crate::dll::az_svg_path_element_vec_new() } }
-        /// Creates a new, empty Rust `Vec<SvgPathElement>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_path_element_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<SvgPathElement>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzSvgPathElement, len: usize) -> Self { unsafe { crate::dll::az_svg_path_element_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for SvgPathElementVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_path_element_vec_deep_copy(self) } } }
-    impl Drop for SvgPathElementVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_path_element_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `SvgVertex`
-    #[doc(inline)] pub use crate::dll::AzSvgVertexVec as SvgVertexVec;
-
-    impl SvgVertexVec {
-        /// Creates a new, empty Rust `Vec<SvgVertex>`
-        pub fn new() -> Self { unsafe { crate::dll::az_svg_vertex_vec_new() } }
-        /// Creates a new, empty Rust `Vec<SvgVertex>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_vertex_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<SvgVertex>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzSvgVertex, len: usize) -> Self { unsafe { crate::dll::az_svg_vertex_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for SvgVertexVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_vertex_vec_deep_copy(self) } } }
-    impl Drop for SvgVertexVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_vertex_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<u32>`
-    #[doc(inline)] pub use crate::dll::AzU32Vec as U32Vec;
-
-    impl U32Vec {
-        /// Creates a new, empty Rust `Vec<u32>`
-        pub fn new() -> Self { unsafe { crate::dll::az_u32_vec_new() } }
-        /// Creates a new, empty Rust `Vec<u32>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_u32_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<u32>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_u32_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for U32Vec { fn clone(&self) -> Self { unsafe { crate::dll::az_u32_vec_deep_copy(self) } } }
-    impl Drop for U32Vec { fn drop(&mut self) { unsafe { crate::dll::az_u32_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `XWindowType`
-    #[doc(inline)] pub use crate::dll::AzXWindowTypeVec as XWindowTypeVec;
-
-    impl XWindowTypeVec {
-        /// Creates a new, empty Rust `Vec<XWindowType>`
-        pub fn new() -> Self { unsafe { crate::dll::az_x_window_type_vec_new() } }
-        /// Creates a new, empty Rust `Vec<XWindowType>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_x_window_type_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<XWindowType>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzXWindowType, len: usize) -> Self { unsafe { crate::dll::az_x_window_type_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for XWindowTypeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_x_window_type_vec_deep_copy(self) } } }
-    impl Drop for XWindowTypeVec { fn drop(&mut self) { unsafe { crate::dll::az_x_window_type_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `VirtualKeyCode`
-    #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVec as VirtualKeyCodeVec;
-
-    impl VirtualKeyCodeVec {
-        /// Creates a new, empty Rust `Vec<VirtualKeyCode>`
-        pub fn new() -> Self { unsafe { crate::dll::az_virtual_key_code_vec_new() } }
-        /// Creates a new, empty Rust `Vec<VirtualKeyCode>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<VirtualKeyCode>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzVirtualKeyCode, len: usize) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for VirtualKeyCodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_deep_copy(self) } } }
-    impl Drop for VirtualKeyCodeVec { fn drop(&mut self) { unsafe { crate::dll::az_virtual_key_code_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `CascadeInfo`
-    #[doc(inline)] pub use crate::dll::AzCascadeInfoVec as CascadeInfoVec;
-
-    impl CascadeInfoVec {
-        /// Creates a new, empty Rust `Vec<CascadeInfo>`
-        pub fn new() -> Self { unsafe { crate::dll::az_cascade_info_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CascadeInfo>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_cascade_info_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CascadeInfo>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCascadeInfo, len: usize) -> Self { unsafe { crate::dll::az_cascade_info_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CascadeInfoVec { fn clone(&self) -> Self { unsafe { crate::dll::az_cascade_info_vec_deep_copy(self) } } }
-    impl Drop for CascadeInfoVec { fn drop(&mut self) { unsafe { crate::dll::az_cascade_info_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `ScanCode`
-    #[doc(inline)] pub use crate::dll::AzScanCodeVec as ScanCodeVec;
-
-    impl ScanCodeVec {
-        /// Creates a new, empty Rust `Vec<ScanCode>`
-        pub fn new() -> Self { unsafe { crate::dll::az_scan_code_vec_new() } }
-        /// Creates a new, empty Rust `Vec<ScanCode>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_scan_code_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<ScanCode>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_scan_code_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for ScanCodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_scan_code_vec_deep_copy(self) } } }
-    impl Drop for ScanCodeVec { fn drop(&mut self) { unsafe { crate::dll::az_scan_code_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `CssDeclaration`
-    #[doc(inline)] pub use crate::dll::AzCssDeclarationVec as CssDeclarationVec;
-
-    impl CssDeclarationVec {
-        /// Creates a new, empty Rust `Vec<CssDeclaration>`
-        pub fn new() -> Self { unsafe { crate::dll::az_css_declaration_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CssDeclaration>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_declaration_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CssDeclaration>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCssDeclaration, len: usize) -> Self { unsafe { crate::dll::az_css_declaration_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CssDeclarationVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_declaration_vec_deep_copy(self) } } }
-    impl Drop for CssDeclarationVec { fn drop(&mut self) { unsafe { crate::dll::az_css_declaration_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `CssPathSelector`
-    #[doc(inline)] pub use crate::dll::AzCssPathSelectorVec as CssPathSelectorVec;
-
-    impl CssPathSelectorVec {
-        /// Creates a new, empty Rust `Vec<CssPathSelector>`
-        pub fn new() -> Self { unsafe { crate::dll::az_css_path_selector_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CssPathSelector>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_path_selector_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CssPathSelector>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCssPathSelector, len: usize) -> Self { unsafe { crate::dll::az_css_path_selector_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CssPathSelectorVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_path_selector_vec_deep_copy(self) } } }
-    impl Drop for CssPathSelectorVec { fn drop(&mut self) { unsafe { crate::dll::az_css_path_selector_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Stylesheet`
-    #[doc(inline)] pub use crate::dll::AzStylesheetVec as StylesheetVec;
-
-    impl StylesheetVec {
-        /// Creates a new, empty Rust `Vec<Stylesheet>`
-        pub fn new() -> Self { unsafe { crate::dll::az_stylesheet_vec_new() } }
-        /// Creates a new, empty Rust `Vec<Stylesheet>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_stylesheet_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<Stylesheet>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStylesheet, len: usize) -> Self { unsafe { crate::dll::az_stylesheet_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StylesheetVec { fn clone(&self) -> Self { unsafe { crate::dll::az_stylesheet_vec_deep_copy(self) } } }
-    impl Drop for StylesheetVec { fn drop(&mut self) { unsafe { crate::dll::az_stylesheet_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `CssRuleBlock`
-    #[doc(inline)] pub use crate::dll::AzCssRuleBlockVec as CssRuleBlockVec;
-
-    impl CssRuleBlockVec {
-        /// Creates a new, empty Rust `Vec<CssRuleBlock>`
-        pub fn new() -> Self { unsafe { crate::dll::az_css_rule_block_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CssRuleBlock>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_rule_block_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CssRuleBlock>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCssRuleBlock, len: usize) -> Self { unsafe { crate::dll::az_css_rule_block_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CssRuleBlockVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_rule_block_vec_deep_copy(self) } } }
-    impl Drop for CssRuleBlockVec { fn drop(&mut self) { unsafe { crate::dll::az_css_rule_block_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `U8Vec`
-    #[doc(inline)] pub use crate::dll::AzU8Vec as U8Vec;
-
-    impl U8Vec {
-        /// Creates a new, empty Rust `Vec<u8>`
-        pub fn new() -> Self { unsafe { crate::dll::az_u8_vec_new() } }
-        /// Creates a new, empty Rust `Vec<u8>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_u8_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<u8>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const u8, len: usize) -> Self { unsafe { crate::dll::az_u8_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for U8Vec { fn clone(&self) -> Self { unsafe { crate::dll::az_u8_vec_deep_copy(self) } } }
-    impl Drop for U8Vec { fn drop(&mut self) { unsafe { crate::dll::az_u8_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `CallbackData`
-    #[doc(inline)] pub use crate::dll::AzCallbackDataVec as CallbackDataVec;
-
-    impl CallbackDataVec {
-        /// Creates a new, empty Rust `Vec<CallbackData>`
-        pub fn new() -> Self { unsafe { crate::dll::az_callback_data_vec_new() } }
-        /// Creates a new, empty Rust `Vec<CallbackData>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_callback_data_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<CallbackData>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzCallbackData, len: usize) -> Self { unsafe { crate::dll::az_callback_data_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for CallbackDataVec { fn clone(&self) -> Self { unsafe { crate::dll::az_callback_data_vec_deep_copy(self) } } }
-    impl Drop for CallbackDataVec { fn drop(&mut self) { unsafe { crate::dll::az_callback_data_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `Vec<DebugMessage>`
-    #[doc(inline)] pub use crate::dll::AzDebugMessageVec as DebugMessageVec;
-
-    impl DebugMessageVec {
-        /// Creates a new, empty Rust `Vec<DebugMessage>`
-        pub fn new() -> Self { unsafe { crate::dll::az_debug_message_vec_new() } }
-        /// Creates a new, empty Rust `Vec<DebugMessage>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_debug_message_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<DebugMessage>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzDebugMessage, len: usize) -> Self { unsafe { crate::dll::az_debug_message_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for DebugMessageVec { fn clone(&self) -> Self { unsafe { crate::dll::az_debug_message_vec_deep_copy(self) } } }
-    impl Drop for DebugMessageVec { fn drop(&mut self) { unsafe { crate::dll::az_debug_message_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `U32Vec`
-    #[doc(inline)] pub use crate::dll::AzGLuintVec as GLuintVec;
-
-    impl GLuintVec {
-        /// Creates a new, empty Rust `Vec<u32>`
-        pub fn new() -> Self { unsafe { crate::dll::az_g_luint_vec_new() } }
-        /// Creates a new, empty Rust `Vec<u32>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_g_luint_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<u32>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_g_luint_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for GLuintVec { fn clone(&self) -> Self { unsafe { crate::dll::az_g_luint_vec_deep_copy(self) } } }
-    impl Drop for GLuintVec { fn drop(&mut self) { unsafe { crate::dll::az_g_luint_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `GLintVec`
-    #[doc(inline)] pub use crate::dll::AzGLintVec as GLintVec;
-
-    impl GLintVec {
-        /// Creates a new, empty Rust `Vec<GLint>`
-        pub fn new() -> Self { unsafe { crate::dll::az_g_lint_vec_new() } }
-        /// Creates a new, empty Rust `Vec<GLint>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_g_lint_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<GLint>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const i32, len: usize) -> Self { unsafe { crate::dll::az_g_lint_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for GLintVec { fn clone(&self) -> Self { unsafe { crate::dll::az_g_lint_vec_deep_copy(self) } } }
-    impl Drop for GLintVec { fn drop(&mut self) { unsafe { crate::dll::az_g_lint_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `StringVec`
-    #[doc(inline)] pub use crate::dll::AzStringVec as StringVec;
-
-    impl StringVec {
-        /// Creates a new, empty Rust `Vec<String>`
-        pub fn new() -> Self { unsafe { crate::dll::az_string_vec_new() } }
-        /// Creates a new, empty Rust `Vec<String>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_string_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<String>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzString, len: usize) -> Self { unsafe { crate::dll::az_string_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StringVec { fn clone(&self) -> Self { unsafe { crate::dll::az_string_vec_deep_copy(self) } } }
-    impl Drop for StringVec { fn drop(&mut self) { unsafe { crate::dll::az_string_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `StringPairVec`
-    #[doc(inline)] pub use crate::dll::AzStringPairVec as StringPairVec;
-
-    impl StringPairVec {
-        /// Creates a new, empty Rust `Vec<StringPair>`
-        pub fn new() -> Self { unsafe { crate::dll::az_string_pair_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StringPair>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_string_pair_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StringPair>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStringPair, len: usize) -> Self { unsafe { crate::dll::az_string_pair_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StringPairVec { fn clone(&self) -> Self { unsafe { crate::dll::az_string_pair_vec_deep_copy(self) } } }
-    impl Drop for StringPairVec { fn drop(&mut self) { unsafe { crate::dll::az_string_pair_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `LinearColorStopVec`
-    #[doc(inline)] pub use crate::dll::AzLinearColorStopVec as LinearColorStopVec;
-
-    impl LinearColorStopVec {
-        /// Creates a new, empty Rust `Vec<LinearColorStop>`
-        pub fn new() -> Self { unsafe { crate::dll::az_linear_color_stop_vec_new() } }
-        /// Creates a new, empty Rust `Vec<LinearColorStop>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<LinearColorStop>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzLinearColorStop, len: usize) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for LinearColorStopVec { fn clone(&self) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_deep_copy(self) } } }
-    impl Drop for LinearColorStopVec { fn drop(&mut self) { unsafe { crate::dll::az_linear_color_stop_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `RadialColorStopVec`
-    #[doc(inline)] pub use crate::dll::AzRadialColorStopVec as RadialColorStopVec;
-
-    impl RadialColorStopVec {
-        /// Creates a new, empty Rust `Vec<RadialColorStop>`
-        pub fn new() -> Self { unsafe { crate::dll::az_radial_color_stop_vec_new() } }
-        /// Creates a new, empty Rust `Vec<RadialColorStop>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<RadialColorStop>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzRadialColorStop, len: usize) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for RadialColorStopVec { fn clone(&self) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_deep_copy(self) } } }
-    impl Drop for RadialColorStopVec { fn drop(&mut self) { unsafe { crate::dll::az_radial_color_stop_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `NodeIdVec`
-    #[doc(inline)] pub use crate::dll::AzNodeIdVec as NodeIdVec;
-
-    impl NodeIdVec {
-        /// Creates a new, empty Rust `Vec<NodeId>`
-        pub fn new() -> Self { unsafe { crate::dll::az_node_id_vec_new() } }
-        /// Creates a new, empty Rust `Vec<NodeId>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_id_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<NodeId>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzNodeId, len: usize) -> Self { unsafe { crate::dll::az_node_id_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for NodeIdVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_id_vec_deep_copy(self) } } }
-    impl Drop for NodeIdVec { fn drop(&mut self) { unsafe { crate::dll::az_node_id_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `NodeVec`
-    #[doc(inline)] pub use crate::dll::AzNodeVec as NodeVec;
-
-    impl NodeVec {
-        /// Creates a new, empty Rust `Vec<Node>`
-        pub fn new() -> Self { unsafe { crate::dll::az_node_vec_new() } }
-        /// Creates a new, empty Rust `Vec<Node>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<Node>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzNode, len: usize) -> Self { unsafe { crate::dll::az_node_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for NodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_vec_deep_copy(self) } } }
-    impl Drop for NodeVec { fn drop(&mut self) { unsafe { crate::dll::az_node_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `StyledNodeVec`
-    #[doc(inline)] pub use crate::dll::AzStyledNodeVec as StyledNodeVec;
-
-    impl StyledNodeVec {
-        /// Creates a new, empty Rust `Vec<StyledNode>`
-        pub fn new() -> Self { unsafe { crate::dll::az_styled_node_vec_new() } }
-        /// Creates a new, empty Rust `Vec<StyledNode>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_styled_node_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<StyledNode>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzStyledNode, len: usize) -> Self { unsafe { crate::dll::az_styled_node_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for StyledNodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_styled_node_vec_deep_copy(self) } } }
-    impl Drop for StyledNodeVec { fn drop(&mut self) { unsafe { crate::dll::az_styled_node_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `TagIdsToNodeIdsMappingVec`
-    #[doc(inline)] pub use crate::dll::AzTagIdsToNodeIdsMappingVec as TagIdsToNodeIdsMappingVec;
-
-    impl TagIdsToNodeIdsMappingVec {
-        /// Creates a new, empty Rust `Vec<TagIdToNodeIdMapping>`
-        pub fn new() -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_new() } }
-        /// Creates a new, empty Rust `Vec<TagIdToNodeIdMapping>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<TagIdToNodeIdMapping>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzTagIdToNodeIdMapping, len: usize) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for TagIdsToNodeIdsMappingVec { fn clone(&self) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_deep_copy(self) } } }
-    impl Drop for TagIdsToNodeIdsMappingVec { fn drop(&mut self) { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
-    #[doc(inline)] pub use crate::dll::AzParentWithNodeDepthVec as ParentWithNodeDepthVec;
-
-    impl ParentWithNodeDepthVec {
-        /// Creates a new, empty Rust `Vec<ParentWithNodeDepth>`
-        pub fn new() -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_new() } }
-        /// Creates a new, empty Rust `Vec<ParentWithNodeDepth>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<ParentWithNodeDepth>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzParentWithNodeDepth, len: usize) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for ParentWithNodeDepthVec { fn clone(&self) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_deep_copy(self) } } }
-    impl Drop for ParentWithNodeDepthVec { fn drop(&mut self) { unsafe { crate::dll::az_parent_with_node_depth_vec_delete(self) }; } }
-
-
-    /// Wrapper over a Rust-allocated `NodeDataVec`
-    #[doc(inline)] pub use crate::dll::AzNodeDataVec as NodeDataVec;
-
-    impl NodeDataVec {
-        /// Creates a new, empty Rust `Vec<NodeData>`
-        pub fn new() -> Self { unsafe { crate::dll::az_node_data_vec_new() } }
-        /// Creates a new, empty Rust `Vec<NodeData>` with a given, pre-allocated capacity
-        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_data_vec_with_capacity(cap) } }
-        /// Creates + allocates a Rust `Vec<NodeData>` by **copying** it from a bytes source
-        pub fn copy_from(ptr: *const AzNodeData, len: usize) -> Self { unsafe { crate::dll::az_node_data_vec_copy_from(ptr, len) } }
-    }
-
-    impl Clone for NodeDataVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_data_vec_deep_copy(self) } } }
-    impl Drop for NodeDataVec { fn drop(&mut self) { unsafe { crate::dll::az_node_data_vec_delete(self) }; } }
+    }    use crate::dom::{CallbackData, Dom, IdOrClass, NodeData, NodeDataInlineCssProperty};
+    use crate::css::{CssDeclaration, CssPathSelector, CssProperty, CssRuleBlock, LinearColorStop, RadialColorStop, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundRepeat, StyleBackgroundSize, StyleTransform, Stylesheet};
+    use crate::svg::{SvgMultiPolygon, SvgPath, SvgPathElement, SvgVertex};
+    use crate::gl::{DebugMessage, VertexAttribute};
+    use crate::window::{StringPair, VirtualKeyCode, XWindowType};
+    use crate::style::{CascadeInfo, Node, ParentWithNodeDepth, StyledNode, TagIdToNodeIdMapping};
+    use crate::str::String;
+    use crate::callbacks::NodeId;
+
+
+    /// Wrapper over a Rust-allocated `Vec<Dom>`
+    #[doc(inline)] pub use crate::dll::AzDomVec as DomVec;
+
+    impl DomVec {
+        /// Creates a new, empty Rust `Vec<Dom>`
+        pub fn new() -> Self { unsafe { crate::dll::az_dom_vec_new() } }
+        /// Creates a new, empty Rust `Vec<Dom>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_dom_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<Dom>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzDom, len: usize) -> Self { unsafe { crate::dll::az_dom_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for DomVec { fn clone(&self) -> Self { unsafe { crate::dll::az_dom_vec_deep_copy(self) } } }
+    impl Drop for DomVec { fn drop(&mut self) { unsafe { crate::dll::az_dom_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<IdOrClass>`
+    #[doc(inline)] pub use crate::dll::AzIdOrClassVec as IdOrClassVec;
+
+    impl IdOrClassVec {
+        /// Creates a new, empty Rust `Vec<IdOrClass>`
+        pub fn new() -> Self { unsafe { crate::dll::az_id_or_class_vec_new() } }
+        /// Creates a new, empty Rust `Vec<IdOrClass>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_id_or_class_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<IdOrClass>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzIdOrClass, len: usize) -> Self { unsafe { crate::dll::az_id_or_class_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for IdOrClassVec { fn clone(&self) -> Self { unsafe { crate::dll::az_id_or_class_vec_deep_copy(self) } } }
+    impl Drop for IdOrClassVec { fn drop(&mut self) { unsafe { crate::dll::az_id_or_class_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
+    #[doc(inline)] pub use crate::dll::AzNodeDataInlineCssPropertyVec as NodeDataInlineCssPropertyVec;
+
+    impl NodeDataInlineCssPropertyVec {
+        /// Creates a new, empty Rust `Vec<NodeDataInlineCssProperty>`
+        pub fn new() -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_new() } }
+        /// Creates a new, empty Rust `Vec<NodeDataInlineCssProperty>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<NodeDataInlineCssProperty>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzNodeDataInlineCssProperty, len: usize) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for NodeDataInlineCssPropertyVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_data_inline_css_property_vec_deep_copy(self) } } }
+    impl Drop for NodeDataInlineCssPropertyVec { fn drop(&mut self) { unsafe { crate::dll::az_node_data_inline_css_property_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundContent>`
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVec as StyleBackgroundContentVec;
+
+    impl StyleBackgroundContentVec {
+        /// Creates a new, empty Rust `Vec<StyleBackgroundContent>`
+        pub fn new() -> Self { unsafe { crate::dll::az_style_background_content_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyleBackgroundContent>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_content_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyleBackgroundContent>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyleBackgroundContent, len: usize) -> Self { unsafe { crate::dll::az_style_background_content_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyleBackgroundContentVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_content_vec_deep_copy(self) } } }
+    impl Drop for StyleBackgroundContentVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_content_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundPosition>`
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundPositionVec as StyleBackgroundPositionVec;
+
+    impl StyleBackgroundPositionVec {
+        /// Creates a new, empty Rust `Vec<StyleBackgroundPosition>`
+        pub fn new() -> Self { unsafe { crate::dll::az_style_background_position_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyleBackgroundPosition>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_position_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyleBackgroundPosition>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyleBackgroundPosition, len: usize) -> Self { unsafe { crate::dll::az_style_background_position_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyleBackgroundPositionVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_position_vec_deep_copy(self) } } }
+    impl Drop for StyleBackgroundPositionVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_position_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundRepeat>`
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundRepeatVec as StyleBackgroundRepeatVec;
+
+    impl StyleBackgroundRepeatVec {
+        /// Creates a new, empty Rust `Vec<StyleBackgroundRepeat>`
+        pub fn new() -> Self { unsafe { crate::dll::az_style_background_repeat_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyleBackgroundRepeat>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyleBackgroundRepeat>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyleBackgroundRepeat, len: usize) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyleBackgroundRepeatVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_repeat_vec_deep_copy(self) } } }
+    impl Drop for StyleBackgroundRepeatVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_repeat_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<StyleBackgroundSize>`
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundSizeVec as StyleBackgroundSizeVec;
+
+    impl StyleBackgroundSizeVec {
+        /// Creates a new, empty Rust `Vec<StyleBackgroundSize>`
+        pub fn new() -> Self { unsafe { crate::dll::az_style_background_size_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyleBackgroundSize>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_background_size_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyleBackgroundSize>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyleBackgroundSize, len: usize) -> Self { unsafe { crate::dll::az_style_background_size_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyleBackgroundSizeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_background_size_vec_deep_copy(self) } } }
+    impl Drop for StyleBackgroundSizeVec { fn drop(&mut self) { unsafe { crate::dll::az_style_background_size_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
+    #[doc(inline)] pub use crate::dll::AzStyleTransformVec as StyleTransformVec;
+
+    impl StyleTransformVec {
+        /// Creates a new, empty Rust `Vec<StyleTransform>`
+        pub fn new() -> Self { unsafe { crate::dll::az_style_transform_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyleTransform>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_style_transform_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyleTransform>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyleTransform, len: usize) -> Self { unsafe { crate::dll::az_style_transform_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyleTransformVec { fn clone(&self) -> Self { unsafe { crate::dll::az_style_transform_vec_deep_copy(self) } } }
+    impl Drop for StyleTransformVec { fn drop(&mut self) { unsafe { crate::dll::az_style_transform_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<CssProperty>`
+    #[doc(inline)] pub use crate::dll::AzCssPropertyVec as CssPropertyVec;
+
+    impl CssPropertyVec {
+        /// Creates a new, empty Rust `Vec<CssProperty>`
+        pub fn new() -> Self { unsafe { crate::dll::az_css_property_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CssProperty>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_property_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CssProperty>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCssProperty, len: usize) -> Self { unsafe { crate::dll::az_css_property_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CssPropertyVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_property_vec_deep_copy(self) } } }
+    impl Drop for CssPropertyVec { fn drop(&mut self) { unsafe { crate::dll::az_css_property_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<SvgMultiPolygon>`
+    #[doc(inline)] pub use crate::dll::AzSvgMultiPolygonVec as SvgMultiPolygonVec;
+
+    impl SvgMultiPolygonVec {
+        /// Creates a new, empty Rust `Vec<SvgMultiPolygon>`
+        pub fn new() -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_new() } }
+        /// Creates a new, empty Rust `Vec<SvgMultiPolygon>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<SvgMultiPolygon>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzSvgMultiPolygon, len: usize) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for SvgMultiPolygonVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_multi_polygon_vec_deep_copy(self) } } }
+    impl Drop for SvgMultiPolygonVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_multi_polygon_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<SvgPath>`
+    #[doc(inline)] pub use crate::dll::AzSvgPathVec as SvgPathVec;
+
+    impl SvgPathVec {
+        /// Creates a new, empty Rust `Vec<SvgPath>`
+        pub fn new() -> Self { unsafe { crate::dll::az_svg_path_vec_new() } }
+        /// Creates a new, empty Rust `Vec<SvgPath>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_path_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<SvgPath>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzSvgPath, len: usize) -> Self { unsafe { crate::dll::az_svg_path_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for SvgPathVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_path_vec_deep_copy(self) } } }
+    impl Drop for SvgPathVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_path_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<VertexAttribute>`
+    #[doc(inline)] pub use crate::dll::AzVertexAttributeVec as VertexAttributeVec;
+
+    impl VertexAttributeVec {
+        /// Creates a new, empty Rust `Vec<VertexAttribute>`
+        pub fn new() -> Self { unsafe { crate::dll::az_vertex_attribute_vec_new() } }
+        /// Creates a new, empty Rust `Vec<VertexAttribute>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<VertexAttribute>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzVertexAttribute, len: usize) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for VertexAttributeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_vertex_attribute_vec_deep_copy(self) } } }
+    impl Drop for VertexAttributeVec { fn drop(&mut self) { unsafe { crate::dll::az_vertex_attribute_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `VertexAttribute`
+    #[doc(inline)] pub use crate::dll::AzSvgPathElementVec as SvgPathElementVec;
+
+    impl SvgPathElementVec {
+        /// Creates a new, empty Rust `Vec<SvgPathElement>`
+        pub fn new() -> Self { unsafe { crate::dll::az_svg_path_element_vec_new() } }
+        /// Creates a new, empty Rust `Vec<SvgPathElement>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_path_element_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<SvgPathElement>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzSvgPathElement, len: usize) -> Self { unsafe { crate::dll::az_svg_path_element_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for SvgPathElementVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_path_element_vec_deep_copy(self) } } }
+    impl Drop for SvgPathElementVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_path_element_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `SvgVertex`
+    #[doc(inline)] pub use crate::dll::AzSvgVertexVec as SvgVertexVec;
+
+    impl SvgVertexVec {
+        /// Creates a new, empty Rust `Vec<SvgVertex>`
+        pub fn new() -> Self { unsafe { crate::dll::az_svg_vertex_vec_new() } }
+        /// Creates a new, empty Rust `Vec<SvgVertex>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_svg_vertex_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<SvgVertex>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzSvgVertex, len: usize) -> Self { unsafe { crate::dll::az_svg_vertex_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for SvgVertexVec { fn clone(&self) -> Self { unsafe { crate::dll::az_svg_vertex_vec_deep_copy(self) } } }
+    impl Drop for SvgVertexVec { fn drop(&mut self) { unsafe { crate::dll::az_svg_vertex_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<u32>`
+    #[doc(inline)] pub use crate::dll::AzU32Vec as U32Vec;
+
+    impl U32Vec {
+        /// Creates a new, empty Rust `Vec<u32>`
+        pub fn new() -> Self { unsafe { crate::dll::az_u32_vec_new() } }
+        /// Creates a new, empty Rust `Vec<u32>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_u32_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<u32>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_u32_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for U32Vec { fn clone(&self) -> Self { unsafe { crate::dll::az_u32_vec_deep_copy(self) } } }
+    impl Drop for U32Vec { fn drop(&mut self) { unsafe { crate::dll::az_u32_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `XWindowType`
+    #[doc(inline)] pub use crate::dll::AzXWindowTypeVec as XWindowTypeVec;
+
+    impl XWindowTypeVec {
+        /// Creates a new, empty Rust `Vec<XWindowType>`
+        pub fn new() -> Self { unsafe { crate::dll::az_x_window_type_vec_new() } }
+        /// Creates a new, empty Rust `Vec<XWindowType>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_x_window_type_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<XWindowType>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzXWindowType, len: usize) -> Self { unsafe { crate::dll::az_x_window_type_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for XWindowTypeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_x_window_type_vec_deep_copy(self) } } }
+    impl Drop for XWindowTypeVec { fn drop(&mut self) { unsafe { crate::dll::az_x_window_type_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `VirtualKeyCode`
+    #[doc(inline)] pub use crate::dll::AzVirtualKeyCodeVec as VirtualKeyCodeVec;
+
+    impl VirtualKeyCodeVec {
+        /// Creates a new, empty Rust `Vec<VirtualKeyCode>`
+        pub fn new() -> Self { unsafe { crate::dll::az_virtual_key_code_vec_new() } }
+        /// Creates a new, empty Rust `Vec<VirtualKeyCode>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<VirtualKeyCode>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzVirtualKeyCode, len: usize) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for VirtualKeyCodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_virtual_key_code_vec_deep_copy(self) } } }
+    impl Drop for VirtualKeyCodeVec { fn drop(&mut self) { unsafe { crate::dll::az_virtual_key_code_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `CascadeInfo`
+    #[doc(inline)] pub use crate::dll::AzCascadeInfoVec as CascadeInfoVec;
+
+    impl CascadeInfoVec {
+        /// Creates a new, empty Rust `Vec<CascadeInfo>`
+        pub fn new() -> Self { unsafe { crate::dll::az_cascade_info_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CascadeInfo>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_cascade_info_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CascadeInfo>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCascadeInfo, len: usize) -> Self { unsafe { crate::dll::az_cascade_info_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CascadeInfoVec { fn clone(&self) -> Self { unsafe { crate::dll::az_cascade_info_vec_deep_copy(self) } } }
+    impl Drop for CascadeInfoVec { fn drop(&mut self) { unsafe { crate::dll::az_cascade_info_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `ScanCode`
+    #[doc(inline)] pub use crate::dll::AzScanCodeVec as ScanCodeVec;
+
+    impl ScanCodeVec {
+        /// Creates a new, empty Rust `Vec<ScanCode>`
+        pub fn new() -> Self { unsafe { crate::dll::az_scan_code_vec_new() } }
+        /// Creates a new, empty Rust `Vec<ScanCode>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_scan_code_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<ScanCode>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_scan_code_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for ScanCodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_scan_code_vec_deep_copy(self) } } }
+    impl Drop for ScanCodeVec { fn drop(&mut self) { unsafe { crate::dll::az_scan_code_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `CssDeclaration`
+    #[doc(inline)] pub use crate::dll::AzCssDeclarationVec as CssDeclarationVec;
+
+    impl CssDeclarationVec {
+        /// Creates a new, empty Rust `Vec<CssDeclaration>`
+        pub fn new() -> Self { unsafe { crate::dll::az_css_declaration_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CssDeclaration>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_declaration_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CssDeclaration>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCssDeclaration, len: usize) -> Self { unsafe { crate::dll::az_css_declaration_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CssDeclarationVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_declaration_vec_deep_copy(self) } } }
+    impl Drop for CssDeclarationVec { fn drop(&mut self) { unsafe { crate::dll::az_css_declaration_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `CssPathSelector`
+    #[doc(inline)] pub use crate::dll::AzCssPathSelectorVec as CssPathSelectorVec;
+
+    impl CssPathSelectorVec {
+        /// Creates a new, empty Rust `Vec<CssPathSelector>`
+        pub fn new() -> Self { unsafe { crate::dll::az_css_path_selector_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CssPathSelector>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_path_selector_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CssPathSelector>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCssPathSelector, len: usize) -> Self { unsafe { crate::dll::az_css_path_selector_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CssPathSelectorVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_path_selector_vec_deep_copy(self) } } }
+    impl Drop for CssPathSelectorVec { fn drop(&mut self) { unsafe { crate::dll::az_css_path_selector_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Stylesheet`
+    #[doc(inline)] pub use crate::dll::AzStylesheetVec as StylesheetVec;
+
+    impl StylesheetVec {
+        /// Creates a new, empty Rust `Vec<Stylesheet>`
+        pub fn new() -> Self { unsafe { crate::dll::az_stylesheet_vec_new() } }
+        /// Creates a new, empty Rust `Vec<Stylesheet>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_stylesheet_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<Stylesheet>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStylesheet, len: usize) -> Self { unsafe { crate::dll::az_stylesheet_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StylesheetVec { fn clone(&self) -> Self { unsafe { crate::dll::az_stylesheet_vec_deep_copy(self) } } }
+    impl Drop for StylesheetVec { fn drop(&mut self) { unsafe { crate::dll::az_stylesheet_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `CssRuleBlock`
+    #[doc(inline)] pub use crate::dll::AzCssRuleBlockVec as CssRuleBlockVec;
+
+    impl CssRuleBlockVec {
+        /// Creates a new, empty Rust `Vec<CssRuleBlock>`
+        pub fn new() -> Self { unsafe { crate::dll::az_css_rule_block_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CssRuleBlock>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_css_rule_block_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CssRuleBlock>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCssRuleBlock, len: usize) -> Self { unsafe { crate::dll::az_css_rule_block_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CssRuleBlockVec { fn clone(&self) -> Self { unsafe { crate::dll::az_css_rule_block_vec_deep_copy(self) } } }
+    impl Drop for CssRuleBlockVec { fn drop(&mut self) { unsafe { crate::dll::az_css_rule_block_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `U8Vec`
+    #[doc(inline)] pub use crate::dll::AzU8Vec as U8Vec;
+
+    impl U8Vec {
+        /// Creates a new, empty Rust `Vec<u8>`
+        pub fn new() -> Self { unsafe { crate::dll::az_u8_vec_new() } }
+        /// Creates a new, empty Rust `Vec<u8>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_u8_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<u8>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const u8, len: usize) -> Self { unsafe { crate::dll::az_u8_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for U8Vec { fn clone(&self) -> Self { unsafe { crate::dll::az_u8_vec_deep_copy(self) } } }
+    impl Drop for U8Vec { fn drop(&mut self) { unsafe { crate::dll::az_u8_vec_delete(self) }; } }
+
+    /// Lets any `std::io::Write`-based encoder stream straight into the
+    /// FFI-owned buffer: `write`/`write_all` go through `extend_from_slice`,
+    /// which reserves once and clones bytes directly into the buffer (no
+    /// intermediate `Vec<u8>`), so repeated small writes via `write!`/
+    /// `io::copy` stay linear instead of O(n²). `flush` is a no-op since
+    /// there is nowhere to flush to.
+    #[cfg(feature = "std")]
+    impl std::io::Write for U8Vec {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            self.extend_from_slice(buf);
+            Ok(())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+
+    /// Wrapper over a Rust-allocated `CallbackData`
+    #[doc(inline)] pub use crate::dll::AzCallbackDataVec as CallbackDataVec;
+
+    impl CallbackDataVec {
+        /// Creates a new, empty Rust `Vec<CallbackData>`
+        pub fn new() -> Self { unsafe { crate::dll::az_callback_data_vec_new() } }
+        /// Creates a new, empty Rust `Vec<CallbackData>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_callback_data_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<CallbackData>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzCallbackData, len: usize) -> Self { unsafe { crate::dll::az_callback_data_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for CallbackDataVec { fn clone(&self) -> Self { unsafe { crate::dll::az_callback_data_vec_deep_copy(self) } } }
+    impl Drop for CallbackDataVec { fn drop(&mut self) { unsafe { crate::dll::az_callback_data_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `Vec<DebugMessage>`
+    #[doc(inline)] pub use crate::dll::AzDebugMessageVec as DebugMessageVec;
+
+    impl DebugMessageVec {
+        /// Creates a new, empty Rust `Vec<DebugMessage>`
+        pub fn new() -> Self { unsafe { crate::dll::az_debug_message_vec_new() } }
+        /// Creates a new, empty Rust `Vec<DebugMessage>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_debug_message_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<DebugMessage>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzDebugMessage, len: usize) -> Self { unsafe { crate::dll::az_debug_message_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for DebugMessageVec { fn clone(&self) -> Self { unsafe { crate::dll::az_debug_message_vec_deep_copy(self) } } }
+    impl Drop for DebugMessageVec { fn drop(&mut self) { unsafe { crate::dll::az_debug_message_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `U32Vec`
+    #[doc(inline)] pub use crate::dll::AzGLuintVec as GLuintVec;
+
+    impl GLuintVec {
+        /// Creates a new, empty Rust `Vec<u32>`
+        pub fn new() -> Self { unsafe { crate::dll::az_g_luint_vec_new() } }
+        /// Creates a new, empty Rust `Vec<u32>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_g_luint_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<u32>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const u32, len: usize) -> Self { unsafe { crate::dll::az_g_luint_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for GLuintVec { fn clone(&self) -> Self { unsafe { crate::dll::az_g_luint_vec_deep_copy(self) } } }
+    impl Drop for GLuintVec { fn drop(&mut self) { unsafe { crate::dll::az_g_luint_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `GLintVec`
+    #[doc(inline)] pub use crate::dll::AzGLintVec as GLintVec;
+
+    impl GLintVec {
+        /// Creates a new, empty Rust `Vec<GLint>`
+        pub fn new() -> Self { unsafe { crate::dll::az_g_lint_vec_new() } }
+        /// Creates a new, empty Rust `Vec<GLint>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_g_lint_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<GLint>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const i32, len: usize) -> Self { unsafe { crate::dll::az_g_lint_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for GLintVec { fn clone(&self) -> Self { unsafe { crate::dll::az_g_lint_vec_deep_copy(self) } } }
+    impl Drop for GLintVec { fn drop(&mut self) { unsafe { crate::dll::az_g_lint_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `StringVec`
+    #[doc(inline)] pub use crate::dll::AzStringVec as StringVec;
+
+    impl StringVec {
+        /// Creates a new, empty Rust `Vec<String>`
+        pub fn new() -> Self { unsafe { crate::dll::az_string_vec_new() } }
+        /// Creates a new, empty Rust `Vec<String>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_string_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<String>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzString, len: usize) -> Self { unsafe { crate::dll::az_string_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StringVec { fn clone(&self) -> Self { unsafe { crate::dll::az_string_vec_deep_copy(self) } } }
+    impl Drop for StringVec { fn drop(&mut self) { unsafe { crate::dll::az_string_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `StringPairVec`
+    #[doc(inline)] pub use crate::dll::AzStringPairVec as StringPairVec;
+
+    impl StringPairVec {
+        /// Creates a new, empty Rust `Vec<StringPair>`
+        pub fn new() -> Self { unsafe { crate::dll::az_string_pair_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StringPair>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_string_pair_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StringPair>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStringPair, len: usize) -> Self { unsafe { crate::dll::az_string_pair_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StringPairVec { fn clone(&self) -> Self { unsafe { crate::dll::az_string_pair_vec_deep_copy(self) } } }
+    impl Drop for StringPairVec { fn drop(&mut self) { unsafe { crate::dll::az_string_pair_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `LinearColorStopVec`
+    #[doc(inline)] pub use crate::dll::AzLinearColorStopVec as LinearColorStopVec;
+
+    impl LinearColorStopVec {
+        /// Creates a new, empty Rust `Vec<LinearColorStop>`
+        pub fn new() -> Self { unsafe { crate::dll::az_linear_color_stop_vec_new() } }
+        /// Creates a new, empty Rust `Vec<LinearColorStop>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<LinearColorStop>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzLinearColorStop, len: usize) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for LinearColorStopVec { fn clone(&self) -> Self { unsafe { crate::dll::az_linear_color_stop_vec_deep_copy(self) } } }
+    impl Drop for LinearColorStopVec { fn drop(&mut self) { unsafe { crate::dll::az_linear_color_stop_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `RadialColorStopVec`
+    #[doc(inline)] pub use crate::dll::AzRadialColorStopVec as RadialColorStopVec;
+
+    impl RadialColorStopVec {
+        /// Creates a new, empty Rust `Vec<RadialColorStop>`
+        pub fn new() -> Self { unsafe { crate::dll::az_radial_color_stop_vec_new() } }
+        /// Creates a new, empty Rust `Vec<RadialColorStop>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<RadialColorStop>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzRadialColorStop, len: usize) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for RadialColorStopVec { fn clone(&self) -> Self { unsafe { crate::dll::az_radial_color_stop_vec_deep_copy(self) } } }
+    impl Drop for RadialColorStopVec { fn drop(&mut self) { unsafe { crate::dll::az_radial_color_stop_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `NodeIdVec`
+    #[doc(inline)] pub use crate::dll::AzNodeIdVec as NodeIdVec;
+
+    impl NodeIdVec {
+        /// Creates a new, empty Rust `Vec<NodeId>`
+        pub fn new() -> Self { unsafe { crate::dll::az_node_id_vec_new() } }
+        /// Creates a new, empty Rust `Vec<NodeId>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_id_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<NodeId>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzNodeId, len: usize) -> Self { unsafe { crate::dll::az_node_id_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for NodeIdVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_id_vec_deep_copy(self) } } }
+    impl Drop for NodeIdVec { fn drop(&mut self) { unsafe { crate::dll::az_node_id_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `NodeVec`
+    #[doc(inline)] pub use crate::dll::AzNodeVec as NodeVec;
+
+    impl NodeVec {
+        /// Creates a new, empty Rust `Vec<Node>`
+        pub fn new() -> Self { unsafe { crate::dll::az_node_vec_new() } }
+        /// Creates a new, empty Rust `Vec<Node>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<Node>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzNode, len: usize) -> Self { unsafe { crate::dll::az_node_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for NodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_vec_deep_copy(self) } } }
+    impl Drop for NodeVec { fn drop(&mut self) { unsafe { crate::dll::az_node_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `StyledNodeVec`
+    #[doc(inline)] pub use crate::dll::AzStyledNodeVec as StyledNodeVec;
+
+    impl StyledNodeVec {
+        /// Creates a new, empty Rust `Vec<StyledNode>`
+        pub fn new() -> Self { unsafe { crate::dll::az_styled_node_vec_new() } }
+        /// Creates a new, empty Rust `Vec<StyledNode>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_styled_node_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<StyledNode>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzStyledNode, len: usize) -> Self { unsafe { crate::dll::az_styled_node_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for StyledNodeVec { fn clone(&self) -> Self { unsafe { crate::dll::az_styled_node_vec_deep_copy(self) } } }
+    impl Drop for StyledNodeVec { fn drop(&mut self) { unsafe { crate::dll::az_styled_node_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `TagIdsToNodeIdsMappingVec`
+    #[doc(inline)] pub use crate::dll::AzTagIdsToNodeIdsMappingVec as TagIdsToNodeIdsMappingVec;
+
+    impl TagIdsToNodeIdsMappingVec {
+        /// Creates a new, empty Rust `Vec<TagIdToNodeIdMapping>`
+        pub fn new() -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_new() } }
+        /// Creates a new, empty Rust `Vec<TagIdToNodeIdMapping>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<TagIdToNodeIdMapping>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzTagIdToNodeIdMapping, len: usize) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for TagIdsToNodeIdsMappingVec { fn clone(&self) -> Self { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_deep_copy(self) } } }
+    impl Drop for TagIdsToNodeIdsMappingVec { fn drop(&mut self) { unsafe { crate::dll::az_tag_ids_to_node_ids_mapping_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
+    #[doc(inline)] pub use crate::dll::AzParentWithNodeDepthVec as ParentWithNodeDepthVec;
+
+    impl ParentWithNodeDepthVec {
+        /// Creates a new, empty Rust `Vec<ParentWithNodeDepth>`
+        pub fn new() -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_new() } }
+        /// Creates a new, empty Rust `Vec<ParentWithNodeDepth>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<ParentWithNodeDepth>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzParentWithNodeDepth, len: usize) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for ParentWithNodeDepthVec { fn clone(&self) -> Self { unsafe { crate::dll::az_parent_with_node_depth_vec_deep_copy(self) } } }
+    impl Drop for ParentWithNodeDepthVec { fn drop(&mut self) { unsafe { crate::dll::az_parent_with_node_depth_vec_delete(self) }; } }
+
+
+    /// Wrapper over a Rust-allocated `NodeDataVec`
+    #[doc(inline)] pub use crate::dll::AzNodeDataVec as NodeDataVec;
+
+    impl NodeDataVec {
+        /// Creates a new, empty Rust `Vec<NodeData>`
+        pub fn new() -> Self { unsafe { crate::dll::az_node_data_vec_new() } }
+        /// Creates a new, empty Rust `Vec<NodeData>` with a given, pre-allocated capacity
+        pub fn with_capacity(cap: usize) -> Self { unsafe { crate::dll::az_node_data_vec_with_capacity(cap) } }
+        /// Creates + allocates a Rust `Vec<NodeData>` by **copying** it from a bytes source
+        pub fn copy_from(ptr: *const AzNodeData, len: usize) -> Self { unsafe { crate::dll::az_node_data_vec_copy_from(ptr, len) } }
+    }
+
+    impl Clone for NodeDataVec { fn clone(&self) -> Self { unsafe { crate::dll::az_node_data_vec_deep_copy(self) } } }
+    impl Drop for NodeDataVec { fn drop(&mut self) { unsafe { crate::dll::az_node_data_vec_delete(self) }; } }
+
+    /// Optional `serde` support for the data-carrying wrappers, gated behind
+    /// the `serde` cargo feature. Each vector serializes as a sequence of its
+    /// elements (borrowed as a slice) and deserializes by collecting back into
+    /// the FFI buffer through the `with_capacity` + `push` path, so a
+    /// round-tripped value owns its allocation and `Drop` frees it normally.
+    #[cfg(feature = "serde")]
+    mod serde_impl {
+        use super::*;
+        use core::fmt;
+        use serde::ser::{Serialize, Serializer, SerializeSeq};
+        use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+
+        macro_rules! impl_vec_serde {($struct_type:ty, $struct_name:ty) => (
+            impl Serialize for $struct_name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let slice: &[$struct_type] = self.as_ref();
+                    let mut seq = serializer.serialize_seq(Some(slice.len()))?;
+                    for element in slice {
+                        seq.serialize_element(element)?;
+                    }
+                    seq.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $struct_name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct VecVisitor;
+                    impl<'de> Visitor<'de> for VecVisitor {
+                        type Value = $struct_name;
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            f.write_str(concat!("a sequence of ", stringify!($struct_type)))
+                        }
+                        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                            let mut out = <$struct_name>::with_capacity(seq.size_hint().unwrap_or(0));
+                            while let Some(element) = seq.next_element()? {
+                                out.push(element);
+                            }
+                            Ok(out)
+                        }
+                    }
+                    deserializer.deserialize_seq(VecVisitor)
+                }
+            }
+        )}
+
+        impl_vec_serde!(AzCssDeclaration, CssDeclarationVec);
+        impl_vec_serde!(AzStylesheet, StylesheetVec);
+        impl_vec_serde!(AzCssRuleBlock, CssRuleBlockVec);
+        impl_vec_serde!(AzString, StringVec);
+        impl_vec_serde!(AzStringPair, StringPairVec);
+        impl_vec_serde!(AzLinearColorStop, LinearColorStopVec);
+        impl_vec_serde!(AzRadialColorStop, RadialColorStopVec);
+        impl_vec_serde!(AzNodeData, NodeDataVec);
+
+        // `U8Vec` rides the dedicated byte-sequence path so bincode/JSON encode
+        // it compactly instead of as a list of integers.
+        impl Serialize for U8Vec {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(self.as_ref())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for U8Vec {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct ByteVisitor;
+                impl<'de> Visitor<'de> for ByteVisitor {
+                    type Value = U8Vec;
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("a byte buffer")
+                    }
+                    fn visit_bytes<E>(self, v: &[u8]) -> Result<U8Vec, E> {
+                        Ok(U8Vec::copy_from(v.as_ptr(), v.len()))
+                    }
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<U8Vec, A::Error> {
+                        let mut out = U8Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                        while let Some(byte) = seq.next_element()? {
+                            out.push(byte);
+                        }
+                        Ok(out)
+                    }
+                }
+                deserializer.deserialize_bytes(ByteVisitor)
+            }
+        }
+    }
@@ -0,0 +1,68 @@
+//! Zero-copy, memory-mapped font loading.
+//!
+//! `parse_font_fn` takes ownership of an in-memory byte buffer and keeps the
+//! whole font resident even when only a handful of tables are read. For fonts
+//! that live on disk we can instead `mmap` the file and parse straight out of
+//! the mapping: the kernel pages tables in on demand and the bytes are never
+//! copied — the zero-copy goal Gecko reaches by wrapping a buffer for `CFData`.
+//!
+//! [`MmapBacking`] is the ref-counted mapping; [`crate::text2::shaping::ParsedFont::from_mmap`]
+//! stores one directly as a struct field, so the mapping is unmapped exactly
+//! when the last `ParsedFont` referencing it drops — which happens from
+//! `parsed_destructor` once the owning `FontRef` is released. There is no
+//! separate leak/release step: ordinary field `Drop` is enough once the
+//! mapping is owned by the parsed font instead of a function-local.
+//!
+//! [`mmapped_source`] is the other consumer: it feeds the `LoadedFontSource`
+//! pipeline that ends up in the external `FontData::bytes` field, which is an
+//! owned FFI buffer and therefore cannot borrow the mapping — that path reads
+//! the mapping once to produce an owned copy of the whole file, so every byte
+//! is read and copied, just as a plain `std::fs::read` does.
+
+use alloc::sync::Arc;
+
+use azul_core::app_resources::LoadedFontSource;
+use memmap2::Mmap;
+
+/// A ref-counted, read-only memory mapping of a font file.
+///
+/// Cloning is cheap (an `Arc` bump); the mapping survives as long as any
+/// clone of this handle does — in particular, as long as the
+/// [`crate::text2::shaping::ParsedFont`] built from it.
+#[derive(Debug, Clone)]
+pub struct MmapBacking {
+    inner: Arc<Mmap>,
+}
+
+impl MmapBacking {
+    /// Maps the file at `path` read-only.
+    pub fn open(path: &str) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the file is opened read-only and the mapping is never
+        // mutated; callers only ever read font tables out of it.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(MmapBacking { inner: Arc::new(mmap) })
+    }
+
+    /// Borrowed view of the mapped bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+/// Builds a [`LoadedFontSource`] by reading `path` through a memory mapping.
+///
+/// The returned source's `data` is an owned copy of the whole file —
+/// `FontData::bytes` is an FFI buffer and has nowhere to hold a borrow — so
+/// every page of the mapping is read, as with `std::fs::read`. Callers that
+/// want the mapping to outlive this call and back parsing directly (no copy
+/// at all) should map the file themselves and use
+/// [`crate::text2::shaping::ParsedFont::from_mmap`] instead.
+pub fn mmapped_source(path: &str, index: u32, load_outlines: bool) -> Option<LoadedFontSource> {
+    let backing = MmapBacking::open(path).ok()?;
+    Some(LoadedFontSource {
+        data: backing.as_slice().into(),
+        index,
+        load_outlines,
+    })
+}
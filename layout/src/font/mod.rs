@@ -0,0 +1,6 @@
+//! Font loading, system-font discovery and zero-copy backing stores.
+
+pub mod mmap;
+pub mod source;
+
+pub use self::source::{FontQuery, FontStyle, FontWeight, SystemFontSource};
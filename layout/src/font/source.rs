@@ -0,0 +1,702 @@
+//! Native system-font discovery.
+//!
+//! Resolving a font by family name, weight and style is inherently
+//! platform-specific: macOS exposes CoreText, Windows exposes DirectWrite and
+//! the free desktops expose Fontconfig. [`SystemFontSource`] hides those three
+//! behind a single `query` method so the rest of the crate only ever sees a
+//! [`LoadedFontSource`] of raw bytes, regardless of where they came from.
+
+use azul_core::app_resources::LoadedFontSource;
+
+/// Requested slant of a face.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl Default for FontStyle {
+    fn default() -> Self {
+        FontStyle::Normal
+    }
+}
+
+/// Requested weight of a face, expressed on the usual 1..=1000 OpenType scale
+/// (400 = regular, 700 = bold).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FontWeight(pub u16);
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight(400)
+    }
+}
+
+/// A request for an installed font: a family name plus the desired style axes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FontQuery {
+    /// Family name as the user typed it, e.g. `"Fira Sans"`. Matched
+    /// case-insensitively and with the platform's own aliasing rules.
+    pub family: String,
+    pub weight: FontWeight,
+    pub style: FontStyle,
+}
+
+impl FontQuery {
+    /// A regular-weight, upright query for `family`.
+    pub fn new(family: &str) -> Self {
+        FontQuery {
+            family: family.to_string(),
+            weight: FontWeight::default(),
+            style: FontStyle::default(),
+        }
+    }
+}
+
+/// Platform-native font enumeration.
+///
+/// The unified-wrapper design mirrors Alacritty's `font` crate, which wraps
+/// CoreText on macOS and FreeType/Fontconfig elsewhere behind one interface.
+pub trait SystemFontSource {
+    /// Resolves `query` to the bytes of the best-matching installed face, or
+    /// `None` if nothing on the system matches.
+    fn query(&self, query: &FontQuery) -> Option<LoadedFontSource>;
+
+    /// Resolves `query` to the `(file path, face index)` of the best match,
+    /// without loading its bytes. Lets a caller that wants a zero-copy
+    /// [`crate::text2::shaping::ParsedFont`] (see `ParsedFont::from_mmap`) mmap
+    /// the file itself instead of going through `query`'s owned buffer.
+    fn resolve_path(&self, query: &FontQuery) -> Option<(String, u32)>;
+}
+
+/// Returns the default system font source for the host platform.
+pub fn system_source() -> impl SystemFontSource {
+    PlatformSource::new()
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    pub use super::coretext::CoreTextSource as PlatformSource;
+}
+#[cfg(target_os = "windows")]
+mod platform {
+    pub use super::directwrite::DirectWriteSource as PlatformSource;
+}
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    pub use super::fontconfig::FontconfigSource as PlatformSource;
+}
+
+use self::platform::PlatformSource;
+
+// ---------------------------------------------------------------------------
+// Linux / BSD: Fontconfig
+// ---------------------------------------------------------------------------
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod fontconfig {
+    use super::{FontQuery, FontStyle, SystemFontSource};
+    use azul_core::app_resources::LoadedFontSource;
+    use std::os::raw::{c_char, c_int};
+    use std::ptr;
+
+    /// A small `const`-correct FFI-utility layer over the Fontconfig C API, in
+    /// the spirit of the `ffi-util` crate. Everything that crosses the FFI
+    /// boundary (`CString`s, `FcPattern`s) is owned by an RAII wrapper so the
+    /// C objects are released on drop even when a query bails out early.
+    mod ffi_util {
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        use super::{sys, FcPattern};
+
+        /// Copy `s` into an owned C string; panics if `s` contains a NUL,
+        /// which a family name or file path never legitimately does.
+        pub fn cstr(s: &str) -> CString {
+            CString::new(s).expect("font query string contained an interior NUL")
+        }
+
+        /// Copy a `const char *` returned by Fontconfig into an owned `String`.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be a valid, NUL-terminated C string for the duration of
+        /// the call (Fontconfig strings live as long as their owning pattern).
+        pub unsafe fn owned(ptr: *const c_char) -> Option<String> {
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        }
+
+        /// Owns an `FcPattern *` and calls `FcPatternDestroy` on drop, so a
+        /// query that bails out early with `?` never leaks the pattern.
+        pub struct OwnedPattern(*mut FcPattern);
+
+        impl OwnedPattern {
+            pub fn new(ptr: *mut FcPattern) -> Option<Self> {
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(OwnedPattern(ptr))
+                }
+            }
+
+            pub fn as_ptr(&self) -> *mut FcPattern {
+                self.0
+            }
+        }
+
+        impl Drop for OwnedPattern {
+            fn drop(&mut self) {
+                unsafe { sys::FcPatternDestroy(self.0) };
+            }
+        }
+    }
+
+    #[repr(C)]
+    pub struct FcConfig {
+        _private: [u8; 0],
+    }
+    #[repr(C)]
+    pub struct FcPattern {
+        _private: [u8; 0],
+    }
+
+    type FcBool = c_int;
+    type FcChar8 = u8;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum FcResult {
+        Match = 0,
+        NoMatch = 1,
+        TypeMismatch = 2,
+        NoId = 3,
+        OutOfMemory = 4,
+    }
+
+    #[repr(C)]
+    enum FcMatchKind {
+        Pattern = 0,
+        Font = 1,
+        Scan = 2,
+    }
+
+    const FC_FAMILY: &[u8] = b"family\0";
+    const FC_WEIGHT: &[u8] = b"weight\0";
+    const FC_SLANT: &[u8] = b"slant\0";
+    const FC_FILE: &[u8] = b"file\0";
+    const FC_INDEX: &[u8] = b"index\0";
+
+    /// Raw bindings to the subset of libfontconfig needed to drive a single
+    /// `FcFontMatch` round-trip; mirrors the real surface the
+    /// `servo-fontconfig` crate exposes.
+    mod sys {
+        use super::{c_char, c_int, FcBool, FcChar8, FcConfig, FcMatchKind, FcPattern, FcResult};
+
+        #[link(name = "fontconfig")]
+        extern "C" {
+            pub fn FcPatternCreate() -> *mut FcPattern;
+            pub fn FcPatternDestroy(p: *mut FcPattern);
+            pub fn FcPatternAddString(
+                p: *mut FcPattern,
+                object: *const c_char,
+                s: *const FcChar8,
+            ) -> FcBool;
+            pub fn FcPatternAddInteger(p: *mut FcPattern, object: *const c_char, i: c_int)
+                -> FcBool;
+            pub fn FcPatternGetString(
+                p: *const FcPattern,
+                object: *const c_char,
+                n: c_int,
+                s: *mut *mut FcChar8,
+            ) -> FcResult;
+            pub fn FcPatternGetInteger(
+                p: *const FcPattern,
+                object: *const c_char,
+                n: c_int,
+                i: *mut c_int,
+            ) -> FcResult;
+            pub fn FcConfigGetCurrent() -> *mut FcConfig;
+            pub fn FcConfigSubstitute(
+                config: *mut FcConfig,
+                p: *mut FcPattern,
+                kind: FcMatchKind,
+            ) -> FcBool;
+            pub fn FcDefaultSubstitute(p: *mut FcPattern);
+            pub fn FcFontMatch(
+                config: *mut FcConfig,
+                p: *mut FcPattern,
+                result: *mut FcResult,
+            ) -> *mut FcPattern;
+            pub fn FcWeightFromOpenType(ot_weight: c_int) -> c_int;
+        }
+    }
+
+    pub struct FontconfigSource;
+
+    impl FontconfigSource {
+        pub fn new() -> Self {
+            FontconfigSource
+        }
+    }
+
+    impl SystemFontSource for FontconfigSource {
+        fn query(&self, query: &FontQuery) -> Option<LoadedFontSource> {
+            // Build an `FcPattern` from the request, let Fontconfig substitute
+            // and match it, then read back the resolved file path and face
+            // index. The raw bindings are the `sys` module above; the unsafe
+            // calls stay inside `resolve` so callers stay safe.
+            let (path, index) = unsafe { resolve(query)? };
+            super::load_from_path(&path, index)
+        }
+
+        fn resolve_path(&self, query: &FontQuery) -> Option<(String, u32)> {
+            unsafe { resolve(query) }
+        }
+    }
+
+    /// Drives `FcFontMatch` and returns the resolved `(file, index)`.
+    ///
+    /// # Safety
+    ///
+    /// Calls into the Fontconfig C library; all owned C objects are released
+    /// through `ffi_util`'s RAII wrappers before returning.
+    unsafe fn resolve(query: &FontQuery) -> Option<(String, u32)> {
+        let pattern = ffi_util::OwnedPattern::new(sys::FcPatternCreate())?;
+
+        let family = ffi_util::cstr(&query.family);
+        sys::FcPatternAddString(
+            pattern.as_ptr(),
+            FC_FAMILY.as_ptr() as *const c_char,
+            family.as_ptr() as *const FcChar8,
+        );
+
+        let slant = match query.style {
+            FontStyle::Normal => 0,
+            FontStyle::Italic => 100,
+            FontStyle::Oblique => 110,
+        };
+        sys::FcPatternAddInteger(pattern.as_ptr(), FC_SLANT.as_ptr() as *const c_char, slant);
+        sys::FcPatternAddInteger(
+            pattern.as_ptr(),
+            FC_WEIGHT.as_ptr() as *const c_char,
+            sys::FcWeightFromOpenType(query.weight.0 as c_int),
+        );
+
+        let config = sys::FcConfigGetCurrent();
+        sys::FcConfigSubstitute(config, pattern.as_ptr(), FcMatchKind::Pattern);
+        sys::FcDefaultSubstitute(pattern.as_ptr());
+
+        let mut result = FcResult::NoMatch;
+        let matched =
+            ffi_util::OwnedPattern::new(sys::FcFontMatch(config, pattern.as_ptr(), &mut result))?;
+        if result != FcResult::Match {
+            return None;
+        }
+
+        let mut file_ptr: *mut FcChar8 = ptr::null_mut();
+        if sys::FcPatternGetString(
+            matched.as_ptr(),
+            FC_FILE.as_ptr() as *const c_char,
+            0,
+            &mut file_ptr,
+        ) != FcResult::Match
+        {
+            return None;
+        }
+        let path = ffi_util::owned(file_ptr as *const c_char)?;
+
+        let mut index: c_int = 0;
+        sys::FcPatternGetInteger(
+            matched.as_ptr(),
+            FC_INDEX.as_ptr() as *const c_char,
+            0,
+            &mut index,
+        );
+
+        Some((path, index.max(0) as u32))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// macOS: CoreText
+// ---------------------------------------------------------------------------
+
+#[cfg(target_os = "macos")]
+mod coretext {
+    use super::{FontQuery, FontStyle, SystemFontSource};
+    use azul_core::app_resources::LoadedFontSource;
+    use std::ffi::{c_void, CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    type CFIndex = isize;
+    type CFStringRef = *const c_void;
+    type CFURLRef = *const c_void;
+    type CTFontDescriptorRef = *const c_void;
+    type CTFontRef = *const c_void;
+    type CTFontSymbolicTraits = u32;
+    type CFStringEncoding = u32;
+    type Boolean = u8;
+
+    const K_CF_STRING_ENCODING_UTF8: CFStringEncoding = 0x0800_0100;
+    const K_CF_URL_POSIX_PATH_STYLE: CFIndex = 0;
+    const CT_FONT_ITALIC_TRAIT: CTFontSymbolicTraits = 1 << 0;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(
+            alloc: *const c_void,
+            c_str: *const c_char,
+            encoding: CFStringEncoding,
+        ) -> CFStringRef;
+        fn CFStringGetLength(string: CFStringRef) -> CFIndex;
+        fn CFStringGetCString(
+            string: CFStringRef,
+            buffer: *mut c_char,
+            buffer_size: CFIndex,
+            encoding: CFStringEncoding,
+        ) -> Boolean;
+        fn CFURLCopyFileSystemPath(url: CFURLRef, path_style: CFIndex) -> CFStringRef;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    #[link(name = "CoreText", kind = "framework")]
+    extern "C" {
+        static kCTFontURLAttribute: CFStringRef;
+
+        fn CTFontDescriptorCreateWithNameAndSize(
+            name: CFStringRef,
+            size: f64,
+        ) -> CTFontDescriptorRef;
+        fn CTFontCreateWithFontDescriptor(
+            descriptor: CTFontDescriptorRef,
+            size: f64,
+            matrix: *const c_void,
+        ) -> CTFontRef;
+        fn CTFontCreateCopyWithSymbolicTraits(
+            font: CTFontRef,
+            size: f64,
+            matrix: *const c_void,
+            sym_trait_value: CTFontSymbolicTraits,
+            sym_trait_mask: CTFontSymbolicTraits,
+        ) -> CTFontRef;
+        fn CTFontCopyAttribute(font: CTFontRef, attribute: CFStringRef) -> *const c_void;
+    }
+
+    /// Owns a `CFTypeRef`/`CTFontRef` for the duration of a query; releases it
+    /// with `CFRelease` on drop so an early `?` never leaks a CoreText object.
+    struct CFOwned(*const c_void);
+
+    impl CFOwned {
+        fn new(ptr: *const c_void) -> Option<Self> {
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CFOwned(ptr))
+            }
+        }
+    }
+
+    impl Drop for CFOwned {
+        fn drop(&mut self) {
+            unsafe { CFRelease(self.0) };
+        }
+    }
+
+    pub struct CoreTextSource;
+
+    impl CoreTextSource {
+        pub fn new() -> Self {
+            CoreTextSource
+        }
+    }
+
+    impl SystemFontSource for CoreTextSource {
+        fn query(&self, query: &FontQuery) -> Option<LoadedFontSource> {
+            let (path, index) = unsafe { resolve(query)? };
+            super::load_from_path(&path, index)
+        }
+
+        fn resolve_path(&self, query: &FontQuery) -> Option<(String, u32)> {
+            unsafe { resolve(query) }
+        }
+    }
+
+    /// Resolves `query` to a file path via `CTFontDescriptorCreateWithNameAndSize`,
+    /// `CTFontCreateCopyWithSymbolicTraits` for the italic/oblique axis, and
+    /// `CTFontCopyAttribute(kCTFontURLAttribute)` for the backing file URL.
+    ///
+    /// CoreText does not expose a face index for the resolved font the way
+    /// Fontconfig/DirectWrite do; the vast majority of installed macOS faces
+    /// are single-face files, so `0` is the correct index in practice.
+    ///
+    /// # Safety
+    ///
+    /// Calls into CoreText/CoreFoundation; all owned `CFTypeRef`s are
+    /// released through [`CFOwned`] before returning.
+    unsafe fn resolve(query: &FontQuery) -> Option<(String, u32)> {
+        let c_family = CString::new(query.family.as_str()).ok()?;
+        let cf_family = CFOwned::new(CFStringCreateWithCString(
+            ptr::null(),
+            c_family.as_ptr(),
+            K_CF_STRING_ENCODING_UTF8,
+        ))?;
+
+        let descriptor = CFOwned::new(CTFontDescriptorCreateWithNameAndSize(cf_family.0, 0.0))?;
+        let base_font = CFOwned::new(CTFontCreateWithFontDescriptor(descriptor.0, 0.0, ptr::null()))?;
+
+        let font = if query.style != FontStyle::Normal {
+            let italic = CTFontCreateCopyWithSymbolicTraits(
+                base_font.0,
+                0.0,
+                ptr::null(),
+                CT_FONT_ITALIC_TRAIT,
+                CT_FONT_ITALIC_TRAIT,
+            );
+            // Falls back to the upright face (and releases it via `unwrap_or`)
+            // if the family has no dedicated italic/oblique member.
+            CFOwned::new(italic).unwrap_or(base_font)
+        } else {
+            base_font
+        };
+
+        let url = CFOwned::new(CTFontCopyAttribute(font.0, kCTFontURLAttribute))?;
+        let cf_path = CFOwned::new(CFURLCopyFileSystemPath(url.0, K_CF_URL_POSIX_PATH_STYLE))?;
+
+        let len = CFStringGetLength(cf_path.0);
+        // UTF-8 is at most 4 bytes per UTF-16 code unit, plus the NUL.
+        let mut buf = vec![0 as c_char; (len as usize) * 4 + 1];
+        if CFStringGetCString(cf_path.0, buf.as_mut_ptr(), buf.len() as CFIndex, K_CF_STRING_ENCODING_UTF8) == 0 {
+            return None;
+        }
+        let path = CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned();
+
+        Some((path, 0))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Windows: DirectWrite
+// ---------------------------------------------------------------------------
+
+#[cfg(target_os = "windows")]
+mod directwrite {
+    use super::{FontQuery, FontStyle, SystemFontSource};
+    use azul_core::app_resources::LoadedFontSource;
+    use std::ffi::c_void;
+    use std::ptr;
+    use winapi::{
+        shared::{
+            minwindef::{FALSE, UINT32},
+            ntdef::HRESULT,
+            winerror::SUCCEEDED,
+        },
+        um::{
+            dwrite::{
+                DWriteCreateFactory, IDWriteFactory, IDWriteFont, IDWriteFontCollection,
+                IDWriteFontFace, IDWriteFontFamily, IDWriteFontFile, IDWriteFontFileLoader,
+                IDWriteLocalFontFileLoader, DWRITE_FACTORY_TYPE_SHARED, DWRITE_FONT_STRETCH_NORMAL,
+                DWRITE_FONT_STYLE, DWRITE_FONT_STYLE_ITALIC, DWRITE_FONT_STYLE_NORMAL,
+                DWRITE_FONT_STYLE_OBLIQUE, DWRITE_FONT_WEIGHT,
+            },
+            unknwnbase::IUnknown,
+        },
+        Interface,
+    };
+
+    /// Releases a COM interface pointer on drop so an early `?` in [`resolve`]
+    /// never leaks a reference.
+    struct ComPtr<T>(*mut T);
+
+    impl<T> ComPtr<T> {
+        fn new(ptr: *mut T) -> Option<Self> {
+            if ptr.is_null() {
+                None
+            } else {
+                Some(ComPtr(ptr))
+            }
+        }
+    }
+
+    impl<T> Drop for ComPtr<T> {
+        fn drop(&mut self) {
+            unsafe {
+                let unknown = self.0 as *mut IUnknown;
+                ((*(*unknown).lpVtbl).Release)(unknown);
+            }
+        }
+    }
+
+    pub struct DirectWriteSource;
+
+    impl DirectWriteSource {
+        pub fn new() -> Self {
+            DirectWriteSource
+        }
+    }
+
+    impl SystemFontSource for DirectWriteSource {
+        fn query(&self, query: &FontQuery) -> Option<LoadedFontSource> {
+            let (path, index) = unsafe { resolve(query)? };
+            super::load_from_path(&path, index)
+        }
+
+        fn resolve_path(&self, query: &FontQuery) -> Option<(String, u32)> {
+            unsafe { resolve(query) }
+        }
+    }
+
+    /// Drives `IDWriteFactory::GetSystemFontCollection` -> `FindFamilyName` ->
+    /// `GetFirstMatchingFont`, then resolves the backing file through
+    /// `IDWriteFontFace::GetFiles` and `IDWriteLocalFontFileLoader::GetFilePathFromKey`.
+    ///
+    /// # Safety
+    ///
+    /// Calls into the DirectWrite COM API; every interface pointer obtained
+    /// along the way is released through [`ComPtr`] before returning, even on
+    /// the early-exit paths.
+    unsafe fn resolve(query: &FontQuery) -> Option<(String, u32)> {
+        let mut factory: *mut IDWriteFactory = ptr::null_mut();
+        let hr = DWriteCreateFactory(
+            DWRITE_FACTORY_TYPE_SHARED,
+            &IDWriteFactory::uuidof(),
+            &mut factory as *mut _ as *mut *mut IUnknown,
+        );
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let factory = ComPtr::new(factory)?;
+
+        let mut collection: *mut IDWriteFontCollection = ptr::null_mut();
+        let hr = (*(*factory.0).lpVtbl).GetSystemFontCollection(factory.0, &mut collection, FALSE);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let collection = ComPtr::new(collection)?;
+
+        let family_name: Vec<u16> = query.family.encode_utf16().chain(Some(0)).collect();
+        let mut family_index: UINT32 = 0;
+        let mut exists = FALSE;
+        let hr = (*(*collection.0).lpVtbl).FindFamilyName(
+            collection.0,
+            family_name.as_ptr(),
+            &mut family_index,
+            &mut exists,
+        );
+        if !SUCCEEDED(hr) || exists == FALSE {
+            return None;
+        }
+
+        let mut family: *mut IDWriteFontFamily = ptr::null_mut();
+        let hr = (*(*collection.0).lpVtbl).GetFontFamily(collection.0, family_index, &mut family);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let family = ComPtr::new(family)?;
+
+        let weight = query.weight.0 as DWRITE_FONT_WEIGHT;
+        let style: DWRITE_FONT_STYLE = match query.style {
+            FontStyle::Normal => DWRITE_FONT_STYLE_NORMAL,
+            FontStyle::Italic => DWRITE_FONT_STYLE_ITALIC,
+            FontStyle::Oblique => DWRITE_FONT_STYLE_OBLIQUE,
+        };
+        let mut font: *mut IDWriteFont = ptr::null_mut();
+        let hr = (*(*family.0).lpVtbl).GetFirstMatchingFont(
+            family.0,
+            weight,
+            DWRITE_FONT_STRETCH_NORMAL,
+            style,
+            &mut font,
+        );
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let font = ComPtr::new(font)?;
+
+        let mut face: *mut IDWriteFontFace = ptr::null_mut();
+        let hr = (*(*font.0).lpVtbl).CreateFontFace(font.0, &mut face);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let face = ComPtr::new(face)?;
+        let face_index = (*(*face.0).lpVtbl).GetIndex(face.0);
+
+        let mut file_count: UINT32 = 1;
+        let mut file: *mut IDWriteFontFile = ptr::null_mut();
+        let hr = (*(*face.0).lpVtbl).GetFiles(face.0, &mut file_count, &mut file);
+        if !SUCCEEDED(hr) || file_count == 0 {
+            return None;
+        }
+        let file = ComPtr::new(file)?;
+
+        let mut key: *const c_void = ptr::null();
+        let mut key_size: UINT32 = 0;
+        let hr = (*(*file.0).lpVtbl).GetReferenceKey(file.0, &mut key, &mut key_size);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+
+        let mut loader: *mut IDWriteFontFileLoader = ptr::null_mut();
+        let hr = (*(*file.0).lpVtbl).GetLoader(file.0, &mut loader);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let loader = ComPtr::new(loader)?;
+
+        let mut local_loader: *mut IDWriteLocalFontFileLoader = ptr::null_mut();
+        let hr = (*(*(loader.0 as *mut IUnknown)).lpVtbl).QueryInterface(
+            loader.0 as *mut IUnknown,
+            &IDWriteLocalFontFileLoader::uuidof(),
+            &mut local_loader as *mut _ as *mut *mut c_void,
+        );
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let local_loader = ComPtr::new(local_loader)?;
+
+        let mut path_len: UINT32 = 0;
+        let hr = (*(*local_loader.0).lpVtbl)
+            .GetFilePathLengthFromKey(local_loader.0, key, key_size, &mut path_len);
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+
+        let mut path_buf: Vec<u16> = vec![0u16; path_len as usize + 1];
+        let hr = (*(*local_loader.0).lpVtbl).GetFilePathFromKey(
+            local_loader.0,
+            key,
+            key_size,
+            path_buf.as_mut_ptr(),
+            path_buf.len() as UINT32,
+        );
+        if !SUCCEEDED(hr) {
+            return None;
+        }
+        let path = String::from_utf16_lossy(&path_buf[..path_len as usize]);
+
+        Some((path, face_index))
+    }
+}
+
+/// Loads the face at `path` (`index` within a collection) into a
+/// [`LoadedFontSource`], reading it through a memory mapping and falling back
+/// to `std::fs::read` if the file cannot be mapped. Both paths return an owned
+/// copy of the file's bytes.
+fn load_from_path(path: &str, index: u32) -> Option<LoadedFontSource> {
+    if let Some(source) = crate::font::mmap::mmapped_source(path, index, true) {
+        return Some(source);
+    }
+    let bytes = std::fs::read(path).ok()?;
+    Some(LoadedFontSource {
+        data: bytes.into(),
+        index,
+        load_outlines: true,
+    })
+}
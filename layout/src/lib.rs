@@ -49,3 +49,47 @@ pub fn parse_font_fn(
         })
     })
 }
+
+/// Resolves a font by family name / weight / style through the platform-native
+/// [`font::source`] resolver and parses the matched face, returning `None` when
+/// nothing on the host system matches the query.
+///
+/// The match is mmapped and parsed directly via
+/// [`text2::shaping::ParsedFont::from_mmap`], so the face's tables are never
+/// copied into a second owned buffer purely to be re-read during parsing;
+/// only `FontData::bytes` — the owned buffer the external FFI type requires —
+/// is. Falls back to the copying [`parse_font_fn`] path if the match can't be
+/// memory-mapped (e.g. a virtual filesystem that doesn't support `mmap`).
+#[cfg(all(feature = "text_layout", feature = "font_loading"))]
+pub fn parse_font_by_name(query: &crate::font::source::FontQuery) -> Option<azul_css::FontRef> {
+    use core::ffi::c_void;
+
+    use crate::font::{
+        mmap::MmapBacking,
+        source::{system_source, SystemFontSource},
+    };
+    use crate::text2::shaping::ParsedFont;
+
+    fn parsed_font_destructor(ptr: *mut c_void) {
+        unsafe {
+            let _ = Box::from_raw(ptr as *mut ParsedFont);
+        }
+    }
+
+    let source = system_source();
+
+    if let Some((path, index)) = source.resolve_path(query) {
+        if let Ok(backing) = MmapBacking::open(&path) {
+            if let Some(parsed_font) = ParsedFont::from_mmap(backing.clone(), index as usize, true) {
+                return Some(azul_css::FontRef::new(azul_css::FontData {
+                    bytes: backing.as_slice().into(),
+                    font_index: index,
+                    parsed: Box::into_raw(Box::new(parsed_font)) as *const c_void,
+                    parsed_destructor: parsed_font_destructor,
+                }));
+            }
+        }
+    }
+
+    parse_font_fn(source.query(query)?)
+}
@@ -0,0 +1,278 @@
+//! Growable texture atlas for rasterized glyph bitmaps.
+//!
+//! Shaping yields glyph IDs, but uploading a freshly rasterized bitmap to the
+//! GPU for every draw is wasteful. [`GlyphAtlas`] packs rasterized glyphs into
+//! one growable texture and hands back the UV rect for a given
+//! `(FontRef, glyph_id, subpixel_bucket, px_size)` key, so each glyph is
+//! rasterized and uploaded at most once.
+//!
+//! Packing uses the skyline bottom-left heuristic from the `font-atlas` glyph
+//! packer: the free space is tracked as a list of horizontal skyline segments
+//! and each glyph is placed at the position minimizing `(y + h, wasted_area)`.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use azul_css::FontRef;
+
+/// Key identifying one rasterized glyph variant in the atlas.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub font: FontRef,
+    pub glyph_id: u16,
+    /// Fractional-pen-position bucket (e.g. 0..4 for quarter-pixel subpixel
+    /// positioning), so hinted glyphs at different subpixel offsets don't alias.
+    pub subpixel_bucket: u8,
+    /// Rasterization size in whole pixels.
+    pub px_size: u16,
+}
+
+/// UV rectangle of a packed glyph, in texels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AtlasRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// A raster bitmap ready to be copied into the atlas texture.
+#[derive(Debug, Clone)]
+pub struct RasterizedGlyph {
+    pub width: u32,
+    pub height: u32,
+    /// Tightly packed coverage/coverage-RGBA bytes, `width * height * stride`.
+    pub bytes: Vec<u8>,
+}
+
+/// One horizontal skyline segment: the free span `[x, x + width)` has its top
+/// edge at `y`.
+#[derive(Debug, Copy, Clone)]
+struct Skyline {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+/// A growable glyph atlas with skyline packing and an LRU eviction hook.
+pub struct GlyphAtlas {
+    size: u32,
+    skyline: Vec<Skyline>,
+    entries: Vec<(GlyphKey, AtlasRect)>,
+    /// Most-recently-used keys, front = least recently used.
+    lru: VecDeque<GlyphKey>,
+    /// Soft cap on packed glyphs; `None` lets the atlas grow unbounded.
+    capacity: Option<usize>,
+}
+
+impl GlyphAtlas {
+    /// Creates an empty atlas of `size * size` texels.
+    pub fn new(size: u32) -> Self {
+        GlyphAtlas {
+            size,
+            skyline: alloc::vec![Skyline { x: 0, y: 0, width: size }],
+            entries: Vec::new(),
+            lru: VecDeque::new(),
+            capacity: None,
+        }
+    }
+
+    /// Sets the maximum number of glyphs retained before the least-recently
+    /// used ones are evicted on the next insert.
+    pub fn with_lru_capacity(mut self, max_glyphs: usize) -> Self {
+        self.capacity = Some(max_glyphs);
+        self
+    }
+
+    /// Current atlas dimension in texels.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Looks up a previously inserted glyph, marking it most-recently used.
+    pub fn get(&mut self, key: &GlyphKey) -> Option<AtlasRect> {
+        let rect = self.entries.iter().find(|(k, _)| k == key).map(|(_, r)| *r)?;
+        self.touch(key);
+        Some(rect)
+    }
+
+    /// Inserts `glyph` under `key`, repacking into a doubled atlas if it does
+    /// not fit, and returns its UV rect. Returns `None` only if a single glyph
+    /// is larger than the largest supported atlas.
+    pub fn insert(&mut self, key: GlyphKey, glyph: &RasterizedGlyph) -> Option<AtlasRect> {
+        if let Some(rect) = self.get(&key) {
+            return Some(rect);
+        }
+
+        self.evict_if_needed();
+
+        loop {
+            if let Some(rect) = self.try_place(glyph.width, glyph.height) {
+                self.entries.push((key.clone(), rect));
+                self.lru.push_back(key);
+                return Some(rect);
+            }
+            if !self.grow() {
+                return None;
+            }
+        }
+    }
+
+    /// Finds the skyline position minimizing `(y + h, wasted_area)` and splices
+    /// the skyline to reflect the placement.
+    fn try_place(&mut self, w: u32, h: u32) -> Option<AtlasRect> {
+        let mut best: Option<(u32, u32, usize, u32)> = None; // (y, wasted, seg_idx, x)
+
+        for idx in 0..self.skyline.len() {
+            if let Some((y, wasted)) = self.fit(idx, w, h) {
+                let x = self.skyline[idx].x;
+                let better = match best {
+                    None => true,
+                    Some((by, bw, _, _)) => (y + h, wasted) < (by + h, bw),
+                };
+                if better {
+                    best = Some((y, wasted, idx, x));
+                }
+            }
+        }
+
+        let (y, _wasted, _idx, x) = best?;
+        self.splice(x, y, w, h);
+        Some(AtlasRect { x, y, width: w, height: h })
+    }
+
+    /// Computes the minimum `y` at which a `w * h` rect placed at segment
+    /// `idx`'s left edge fits under the atlas height, plus the wasted area
+    /// below the rect, or `None` if it runs off the right edge or the top.
+    fn fit(&self, idx: usize, w: u32, h: u32) -> Option<(u32, u32)> {
+        let x = self.skyline[idx].x;
+        if x + w > self.size {
+            return None;
+        }
+
+        let mut y = 0;
+        let mut remaining = w;
+        let mut wasted = 0;
+        let mut i = idx;
+        while remaining > 0 {
+            let seg = self.skyline.get(i)?;
+            y = y.max(seg.y);
+            let used = remaining.min(seg.width);
+            remaining -= used;
+            i += 1;
+        }
+
+        if y + h > self.size {
+            return None;
+        }
+
+        // Area below the rect that the raised skyline will waste.
+        let mut i = idx;
+        let mut remaining = w;
+        while remaining > 0 {
+            let seg = &self.skyline[i];
+            let used = remaining.min(seg.width);
+            wasted += (y - seg.y) * used;
+            remaining -= used;
+            i += 1;
+        }
+
+        Some((y, wasted))
+    }
+
+    /// Raises the skyline over `[x, x + w)` to `y + h` and merges neighbours of
+    /// equal height.
+    fn splice(&mut self, x: u32, y: u32, w: u32, h: u32) {
+        let top = y + h;
+        let mut next: Vec<Skyline> = Vec::with_capacity(self.skyline.len() + 1);
+
+        for seg in &self.skyline {
+            let seg_end = seg.x + seg.width;
+            if seg_end <= x || seg.x >= x + w {
+                next.push(*seg);
+                continue;
+            }
+            // Left remainder of the segment.
+            if seg.x < x {
+                next.push(Skyline { x: seg.x, y: seg.y, width: x - seg.x });
+            }
+            // Right remainder of the segment.
+            if seg_end > x + w {
+                next.push(Skyline { x: x + w, y: seg.y, width: seg_end - (x + w) });
+            }
+        }
+
+        next.push(Skyline { x, y: top, width: w });
+        next.sort_by_key(|s| s.x);
+
+        // Merge adjacent, equal-height segments.
+        self.skyline.clear();
+        for seg in next {
+            match self.skyline.last_mut() {
+                Some(last) if last.y == seg.y && last.x + last.width == seg.x => {
+                    last.width += seg.width;
+                }
+                _ => self.skyline.push(seg),
+            }
+        }
+    }
+
+    /// Doubles the atlas dimension and repacks every retained glyph. Returns
+    /// `false` once the atlas hits the hardware texture limit (16384).
+    fn grow(&mut self) -> bool {
+        const MAX_SIZE: u32 = 16384;
+        if self.size >= MAX_SIZE {
+            return false;
+        }
+
+        self.size *= 2;
+        self.repack();
+        true
+    }
+
+    /// Resets the skyline to a single free segment spanning the atlas and
+    /// re-places every surviving entry. Placement always succeeds: each
+    /// survivor already fit at this size before the reset, and a reset-then-
+    /// replace in insertion order can only pack at least as tightly as
+    /// before. Shared by [`grow`](Self::grow) (repack at the doubled size)
+    /// and [`evict_if_needed`](Self::evict_if_needed) (repack after dropping
+    /// entries, so the space they held becomes reusable instead of staying
+    /// permanently raised on the skyline).
+    fn repack(&mut self) {
+        self.skyline = alloc::vec![Skyline { x: 0, y: 0, width: self.size }];
+        let entries = core::mem::take(&mut self.entries);
+        for (key, rect) in entries {
+            if let Some(r) = self.try_place(rect.width, rect.height) {
+                self.entries.push((key, r));
+            }
+        }
+    }
+
+    /// Marks `key` as most-recently used.
+    fn touch(&mut self, key: &GlyphKey) {
+        if let Some(pos) = self.lru.iter().position(|k| k == key) {
+            let k = self.lru.remove(pos).unwrap();
+            self.lru.push_back(k);
+        }
+    }
+
+    /// Evicts least-recently-used glyphs until the atlas is back under its
+    /// configured capacity, then repacks the skyline from the survivors so
+    /// the space the evicted glyphs held is actually reusable (otherwise it
+    /// stays raised on the skyline forever and every later insert keeps
+    /// driving `grow()` regardless of how much was evicted).
+    fn evict_if_needed(&mut self) {
+        let Some(cap) = self.capacity else { return };
+        let mut evicted = false;
+        while self.entries.len() >= cap {
+            let Some(victim) = self.lru.pop_front() else { break };
+            let before = self.entries.len();
+            self.entries.retain(|(k, _)| *k != victim);
+            evicted |= self.entries.len() != before;
+        }
+        if evicted {
+            self.repack();
+        }
+    }
+}
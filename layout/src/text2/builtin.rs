@@ -0,0 +1,30 @@
+//! Embedded last-resort font.
+//!
+//! When no font source is supplied or system resolution fails, layout has
+//! nothing to shape with and `parse_font_fn` returns `None`. To guarantee the
+//! shaper and the fallback chain always have a terminal face, a small
+//! last-resort font is compiled directly into the crate behind the
+//! `builtin_font` feature — the same always-available default Neovide embeds.
+//!
+//! The feature gate keeps the bytes out of `no_std` / size-sensitive builds
+//! that would rather fail loudly than carry a ~100 KB font.
+
+#![cfg(feature = "builtin_font")]
+
+use crate::text2::shaping::ParsedFont;
+
+/// Raw bytes of the bundled last-resort face.
+static LAST_RESORT_TTF: &[u8] =
+    include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fonts/LastResort.ttf"));
+
+impl ParsedFont {
+    /// Parses the crate's built-in last-resort face.
+    ///
+    /// This never hits the filesystem or the OS, so it is the one face that is
+    /// always loadable; the fallback chain terminates on it, so shaping never
+    /// hard-fails. Returns `None` only if the bundled bytes fail to parse,
+    /// which indicates a corrupt build artifact.
+    pub fn last_resort() -> Option<ParsedFont> {
+        ParsedFont::from_bytes(LAST_RESORT_TTF, 0, true)
+    }
+}
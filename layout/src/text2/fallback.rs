@@ -0,0 +1,147 @@
+//! Per-codepoint font fallback for the shaper.
+//!
+//! A single face rarely covers every script a UI throws at it; any codepoint
+//! the active face lacks shapes to `.notdef` (tofu). This module builds a
+//! compact coverage index from each font's `cmap` and uses it to segment a
+//! string into runs, each tagged with the first font in an ordered fallback
+//! list that covers the run's base codepoints — the same code-points-driven
+//! resolution Fuchsia's font service performs.
+
+use alloc::vec::Vec;
+
+/// A compact, sorted set of covered codepoint ranges.
+///
+/// Built once from a font's `cmap` at parse time and stored on `ParsedFont`.
+/// Ranges are inclusive and kept sorted and non-overlapping so that coverage
+/// tests are a binary search rather than a hash lookup per glyph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageIndex {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CoverageIndex {
+    /// Builds a coverage index from a list of covered codepoints (the
+    /// keys of a `cmap` subtable), coalescing adjacent values into ranges.
+    pub fn from_codepoints(mut codepoints: Vec<u32>) -> Self {
+        codepoints.sort_unstable();
+        codepoints.dedup();
+
+        let mut ranges: Vec<(u32, u32)> = Vec::new();
+        for cp in codepoints {
+            match ranges.last_mut() {
+                Some(last) if cp == last.1 + 1 => last.1 = cp,
+                Some(last) if cp <= last.1 => {}
+                _ => ranges.push((cp, cp)),
+            }
+        }
+
+        CoverageIndex { ranges }
+    }
+
+    /// Returns `true` if `codepoint` is covered by the face.
+    pub fn covers(&self, codepoint: u32) -> bool {
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if codepoint < start {
+                    core::cmp::Ordering::Greater
+                } else if codepoint > end {
+                    core::cmp::Ordering::Less
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Number of distinct codepoints covered.
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end - start + 1) as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// Implemented by a parsed, shapeable face to expose its [`CoverageIndex`]
+/// without depending on `text2::shaping::ParsedFont` directly.
+/// [`segment_runs`] takes the coverage indices themselves, not this trait.
+pub trait FontCoverage {
+    fn coverage(&self) -> &CoverageIndex;
+}
+
+/// A contiguous slice of the input that should be shaped with a single face.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackRun {
+    /// Byte offset of the run within the original string.
+    pub start: usize,
+    /// Byte offset one past the end of the run.
+    pub end: usize,
+    /// Index into the fallback list of the font that covers this run, or
+    /// `usize::MAX` for the terminal last-resort face.
+    pub font: usize,
+}
+
+/// Index reserved for the built-in last-resort face that terminates the chain.
+pub const LAST_RESORT: usize = usize::MAX;
+
+/// Segments `text` into runs keyed by which font in `coverage` (an ordered
+/// fallback list, most-preferred first) first covers each cluster's base
+/// codepoint.
+///
+/// Combining marks in the ranges that `is_combining_mark` lists are kept with
+/// the base they follow, so such a mark never splits from its base across
+/// faces. Other `Mn`/`Mc`/`Me` codepoints are not recognised as marks and are
+/// segmented like base codepoints. A
+/// codepoint no font covers falls through to [`LAST_RESORT`], which the caller
+/// guarantees always resolves, so the segmentation always terminates.
+pub fn segment_runs(text: &str, coverage: &[CoverageIndex]) -> Vec<FallbackRun> {
+    let mut runs: Vec<FallbackRun> = Vec::new();
+
+    for (offset, ch) in text.char_indices() {
+        let cp = ch as u32;
+
+        // Combining marks inherit the font of the base they attach to.
+        if is_combining_mark(cp) {
+            if let Some(last) = runs.last_mut() {
+                last.end = offset + ch.len_utf8();
+                continue;
+            }
+        }
+
+        let font = coverage
+            .iter()
+            .position(|c| c.covers(cp))
+            .unwrap_or(LAST_RESORT);
+
+        match runs.last_mut() {
+            Some(last) if last.font == font => last.end = offset + ch.len_utf8(),
+            _ => runs.push(FallbackRun {
+                start: offset,
+                end: offset + ch.len_utf8(),
+                font,
+            }),
+        }
+    }
+
+    runs
+}
+
+/// Returns `true` for the combining-mark ranges that must stay with their base
+/// glyph: the common Latin/diacritic and CJK-mark ranges below. It is a coarse
+/// check with no `UnicodeData` table; combining marks outside these ranges
+/// return `false`.
+fn is_combining_mark(cp: u32) -> bool {
+    matches!(cp,
+        0x0300..=0x036F   // combining diacritical marks
+        | 0x1AB0..=0x1AFF // combining diacritical marks extended
+        | 0x1DC0..=0x1DFF // combining diacritical marks supplement
+        | 0x20D0..=0x20FF // combining diacritical marks for symbols
+        | 0x302A..=0x302F // ideographic and Hangul tone marks
+        | 0x3099..=0x309A // combining kana voiced sound marks
+        | 0xFE20..=0xFE2F // combining half marks
+    )
+}
@@ -0,0 +1,11 @@
+//! Text shaping, layout and glyph caching.
+
+pub mod shaping;
+
+pub mod fallback;
+
+#[cfg(feature = "builtin_font")]
+pub mod builtin;
+
+#[cfg(feature = "font_loading")]
+pub mod atlas;
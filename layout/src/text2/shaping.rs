@@ -0,0 +1,359 @@
+//! SFNT (TrueType/OpenType) table parsing and per-codepoint shaping.
+//!
+//! [`ParsedFont`] owns the raw face bytes plus the handful of tables shaping
+//! actually needs: `cmap` (codepoint -> glyph mapping, and the
+//! [`CoverageIndex`] built from it at parse time, per
+//! [`crate::text2::fallback`]) and `maxp`/`head` for the glyph count and
+//! unitsPerEm the rest of the crate asks about. Heavier OpenType layout
+//! (GSUB/GPOS, kerning, complex scripts) is out of scope here; this is the
+//! level of shaping the fallback segmentation needs to pick a glyph per
+//! cluster.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::text2::fallback::{segment_runs, CoverageIndex, FallbackRun, FontCoverage, LAST_RESORT};
+
+/// A single shaped glyph: which glyph in the face's `glyf`/`CFF` table to
+/// draw, and the byte offset of the cluster it came from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ShapedGlyph {
+    pub glyph_id: u16,
+    /// Byte offset, within the text passed to `shape_run`/`shape_text_with_fallback`,
+    /// of the first codepoint of the cluster this glyph covers.
+    pub cluster: usize,
+}
+
+/// The table bytes backing a [`ParsedFont`]: either an owned heap buffer, or
+/// (behind `font_loading`) a zero-copy memory mapping.
+#[derive(Debug, Clone)]
+enum FontBytes {
+    Owned(Arc<[u8]>),
+    #[cfg(feature = "font_loading")]
+    Mapped(crate::font::mmap::MmapBacking),
+}
+
+impl FontBytes {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            FontBytes::Owned(bytes) => bytes,
+            #[cfg(feature = "font_loading")]
+            FontBytes::Mapped(backing) => backing.as_slice(),
+        }
+    }
+}
+
+/// A parsed SFNT face (a bare TrueType/OpenType font, or one member of a
+/// `ttcf` collection).
+#[derive(Debug, Clone)]
+pub struct ParsedFont {
+    data: FontBytes,
+    font_index: usize,
+    load_outlines: bool,
+    units_per_em: u16,
+    num_glyphs: u16,
+    cmap: BTreeMap<u32, u16>,
+    coverage: CoverageIndex,
+}
+
+impl ParsedFont {
+    /// Parses the `font_index`'th face out of an owned byte buffer, copying
+    /// `data` once into the returned font. Use this when there is no file
+    /// backing the bytes (e.g. the embedded last-resort face); for a font
+    /// that lives on disk, [`ParsedFont::from_mmap`] avoids that copy.
+    ///
+    /// Returns `None` if `data` is not a recognizable SFNT blob or has no
+    /// usable Unicode `cmap` subtable.
+    pub fn from_bytes(data: &[u8], font_index: usize, load_outlines: bool) -> Option<ParsedFont> {
+        Self::from_source(FontBytes::Owned(Arc::from(data)), font_index, load_outlines)
+    }
+
+    /// Parses the `font_index`'th face directly out of a memory mapping,
+    /// without copying the file into a second owned buffer. `backing` is
+    /// stored on the returned font, so the mapping stays resident for exactly
+    /// as long as the `ParsedFont` does (and is unmapped when it drops).
+    #[cfg(feature = "font_loading")]
+    pub fn from_mmap(
+        backing: crate::font::mmap::MmapBacking,
+        font_index: usize,
+        load_outlines: bool,
+    ) -> Option<ParsedFont> {
+        Self::from_source(FontBytes::Mapped(backing), font_index, load_outlines)
+    }
+
+    fn from_source(source: FontBytes, font_index: usize, load_outlines: bool) -> Option<ParsedFont> {
+        let data = source.as_slice();
+
+        let dir_offset = sfnt_table_directory_offset(data, font_index)?;
+        let tables = read_table_records(data, dir_offset)?;
+
+        let cmap_table = tables.get(b"cmap")?;
+        let cmap = parse_cmap(&data[cmap_table.offset as usize..])?;
+        let coverage = CoverageIndex::from_codepoints(cmap.keys().copied().collect());
+
+        let units_per_em = tables
+            .get(b"head")
+            .and_then(|head| read_u16(data, head.offset as usize + 18))
+            .unwrap_or(1000);
+        let num_glyphs = tables
+            .get(b"maxp")
+            .and_then(|maxp| read_u16(data, maxp.offset as usize + 4))
+            .unwrap_or(0);
+
+        Some(ParsedFont {
+            data: source,
+            font_index,
+            load_outlines,
+            units_per_em,
+            num_glyphs,
+            cmap,
+            coverage,
+        })
+    }
+
+    pub fn font_index(&self) -> usize {
+        self.font_index
+    }
+
+    pub fn load_outlines(&self) -> bool {
+        self.load_outlines
+    }
+
+    pub fn units_per_em(&self) -> u16 {
+        self.units_per_em
+    }
+
+    pub fn num_glyphs(&self) -> u16 {
+        self.num_glyphs
+    }
+
+    /// The face's coverage index, as built from its `cmap` at parse time.
+    pub fn coverage_index(&self) -> &CoverageIndex {
+        &self.coverage
+    }
+
+    /// Looks up the glyph for a single codepoint, or `.notdef` (glyph `0`) if
+    /// the face's `cmap` does not cover it.
+    pub fn glyph_for_codepoint(&self, codepoint: u32) -> u16 {
+        self.cmap.get(&codepoint).copied().unwrap_or(0)
+    }
+
+    /// Shapes `text` against this single face with no fallback: each
+    /// character becomes one cluster, mapped through `cmap`.
+    pub fn shape_run(&self, text: &str) -> Vec<ShapedGlyph> {
+        text.char_indices()
+            .map(|(offset, ch)| ShapedGlyph {
+                glyph_id: self.glyph_for_codepoint(ch as u32),
+                cluster: offset,
+            })
+            .collect()
+    }
+}
+
+impl FontCoverage for ParsedFont {
+    fn coverage(&self) -> &CoverageIndex {
+        &self.coverage
+    }
+}
+
+/// Segments `text` by which font in `fonts` (most-preferred first) covers
+/// each cluster — see [`crate::text2::fallback::segment_runs`] — then shapes
+/// each run with its chosen face, falling through to `last_resort` for any
+/// run no font in `fonts` covers.
+pub fn shape_text_with_fallback(
+    text: &str,
+    fonts: &[ParsedFont],
+    last_resort: Option<&ParsedFont>,
+) -> Vec<(FallbackRun, Vec<ShapedGlyph>)> {
+    let coverage: Vec<CoverageIndex> = fonts.iter().map(|f| f.coverage.clone()).collect();
+
+    segment_runs(text, &coverage)
+        .into_iter()
+        .map(|run| {
+            let face = if run.font == LAST_RESORT {
+                last_resort
+            } else {
+                fonts.get(run.font)
+            };
+            let glyphs = match face {
+                Some(font) => font
+                    .shape_run(&text[run.start..run.end])
+                    .into_iter()
+                    .map(|g| ShapedGlyph { cluster: g.cluster + run.start, ..g })
+                    .collect(),
+                None => text[run.start..run.end]
+                    .char_indices()
+                    .map(|(offset, _)| ShapedGlyph {
+                        glyph_id: 0,
+                        cluster: run.start + offset,
+                    })
+                    .collect(),
+            };
+            (run, glyphs)
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// SFNT table directory / cmap parsing
+// ---------------------------------------------------------------------------
+
+struct TableRecord {
+    offset: u32,
+}
+
+struct TableDirectory(BTreeMap<[u8; 4], TableRecord>);
+
+impl TableDirectory {
+    fn get(&self, tag: &[u8; 4]) -> Option<&TableRecord> {
+        self.0.get(tag)
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|v| v as i16)
+}
+
+/// Returns the byte offset of the table directory for `font_index`, handling
+/// both a bare SFNT and a `ttcf` TrueType collection.
+fn sfnt_table_directory_offset(data: &[u8], font_index: usize) -> Option<usize> {
+    if data.get(0..4) == Some(b"ttcf") {
+        let num_fonts = read_u32(data, 8)? as usize;
+        if font_index >= num_fonts {
+            return None;
+        }
+        Some(read_u32(data, 12 + font_index * 4)? as usize)
+    } else {
+        Some(0)
+    }
+}
+
+fn read_table_records(data: &[u8], dir_offset: usize) -> Option<TableDirectory> {
+    let num_tables = read_u16(data, dir_offset + 4)? as usize;
+    let mut tables = BTreeMap::new();
+    for i in 0..num_tables {
+        let record_offset = dir_offset + 12 + i * 16;
+        let mut tag = [0u8; 4];
+        tag.copy_from_slice(data.get(record_offset..record_offset + 4)?);
+        let offset = read_u32(data, record_offset + 8)?;
+        tables.insert(tag, TableRecord { offset });
+    }
+    Some(TableDirectory(tables))
+}
+
+/// Parses the best Unicode `cmap` subtable (format 12 for full Unicode
+/// coverage where present, otherwise format 4's BMP-only mapping) into a
+/// codepoint -> glyph-id table.
+fn parse_cmap(cmap: &[u8]) -> Option<BTreeMap<u32, u16>> {
+    let num_subtables = read_u16(cmap, 2)? as usize;
+
+    let mut best: Option<(u16, u32)> = None; // (format priority, subtable offset)
+    for i in 0..num_subtables {
+        let record = 4 + i * 8;
+        let platform_id = read_u16(cmap, record)?;
+        let encoding_id = read_u16(cmap, record + 2)?;
+        let offset = read_u32(cmap, record + 4)?;
+
+        // (3, 1)/(3, 10) = Windows Unicode BMP/full; (0, _) = any Unicode platform.
+        if !matches!((platform_id, encoding_id), (3, 1) | (3, 10) | (0, _)) {
+            continue;
+        }
+        let format = read_u16(cmap, offset as usize)?;
+        let priority = match format {
+            12 => 2,
+            4 => 1,
+            _ => continue,
+        };
+        if best.map_or(true, |(p, _)| priority > p) {
+            best = Some((priority, offset));
+        }
+    }
+
+    let (_, offset) = best?;
+    match read_u16(cmap, offset as usize)? {
+        4 => parse_cmap_format4(&cmap[offset as usize..]),
+        12 => parse_cmap_format12(&cmap[offset as usize..]),
+        _ => None,
+    }
+}
+
+/// Format 4: segmented BMP mapping (`end[]`/`start[]`/`idDelta[]`/`idRangeOffset[]`).
+fn parse_cmap_format4(table: &[u8]) -> Option<BTreeMap<u32, u16>> {
+    let seg_count_x2 = read_u16(table, 6)? as usize;
+    let seg_count = seg_count_x2 / 2;
+
+    let end_codes = 14;
+    let start_codes = end_codes + seg_count_x2 + 2;
+    let id_deltas = start_codes + seg_count_x2;
+    let id_range_offsets = id_deltas + seg_count_x2;
+
+    let mut map = BTreeMap::new();
+    for seg in 0..seg_count {
+        let end = read_u16(table, end_codes + seg * 2)?;
+        let start = read_u16(table, start_codes + seg * 2)?;
+        let delta = read_i16(table, id_deltas + seg * 2)?;
+        let range_offset = read_u16(table, id_range_offsets + seg * 2)?;
+
+        // The terminal sentinel segment; every real cmap ends with one.
+        if start == 0xFFFF && end == 0xFFFF {
+            continue;
+        }
+
+        let mut cp = start;
+        loop {
+            let glyph = if range_offset == 0 {
+                (cp as i32 + delta as i32) as u16
+            } else {
+                let addr = id_range_offsets + seg * 2 + range_offset as usize + (cp - start) as usize * 2;
+                match read_u16(table, addr)? {
+                    0 => 0,
+                    g => (g as i32 + delta as i32) as u16,
+                }
+            };
+            if glyph != 0 {
+                map.insert(cp as u32, glyph);
+            }
+            if cp == end {
+                break;
+            }
+            cp += 1;
+        }
+    }
+
+    Some(map)
+}
+
+/// Format 12: segmented coverage over the full Unicode range.
+fn parse_cmap_format12(table: &[u8]) -> Option<BTreeMap<u32, u16>> {
+    let num_groups = read_u32(table, 12)? as usize;
+    let mut map = BTreeMap::new();
+
+    for i in 0..num_groups {
+        let group = 16 + i * 12;
+        let start_char = read_u32(table, group)?;
+        let end_char = read_u32(table, group + 4)?;
+        let start_glyph = read_u32(table, group + 8)?;
+
+        let mut cp = start_char;
+        loop {
+            map.insert(cp, (start_glyph + (cp - start_char)) as u16);
+            if cp == end_char {
+                break;
+            }
+            cp += 1;
+        }
+    }
+
+    Some(map)
+}